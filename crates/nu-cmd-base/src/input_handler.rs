@@ -1,5 +1,6 @@
 use nu_protocol::ast::CellPath;
-use nu_protocol::{PipelineData, ShellError, Span, Value};
+use nu_protocol::{IntoPipelineData, PipelineData, ShellError, Span, Value};
+use rayon::prelude::*;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
@@ -86,3 +87,109 @@ where
         }
     }
 }
+
+/// Parallel counterpart to [`operate`], for commands whose per-element work is CPU-heavy
+/// enough that large tables benefit from being split across threads. `rayon`'s
+/// `into_par_iter`/`collect` round-trip keeps elements in their original positions, so
+/// output order matches the sequential path. This collects `input` into a `Vec` up front, so
+/// unlike `operate` it isn't lazy/streaming and doesn't take a `ctrlc` flag to check per
+/// element.
+pub fn operate_parallel<C, A>(
+    cmd: C,
+    mut arg: A,
+    input: PipelineData,
+    span: Span,
+) -> Result<PipelineData, ShellError>
+where
+    A: CmdArgument + Send + Sync + 'static,
+    C: Fn(&Value, &A, Span) -> Value + Send + Sync + 'static + Clone + Copy,
+{
+    // A bare scalar has nothing to parallelize, and `into_iter_strict` below only accepts
+    // list or range values as a genuine sequence of elements -- it explodes a `Value::Binary`
+    // into one `Value::Int` per byte, which isn't what we want here, so binary must be treated
+    // as a scalar too, just like `operate`'s `PipelineData::map` does. Fall back to applying
+    // `cmd` directly, mirroring `operate`'s scalar branch, instead of hard-erroring (or, for
+    // binary, silently running `cmd` once per byte) on input `operate` handles fine.
+    if let PipelineData::Value(value, ..) = &input {
+        if !matches!(value, Value::List { .. } | Value::Range { .. } | Value::Error { .. }) {
+            let PipelineData::Value(v, ..) = input else {
+                unreachable!("just matched PipelineData::Value above")
+            };
+            return Ok(apply_one(cmd, &mut arg, v, span).into_pipeline_data());
+        }
+    }
+
+    let metadata = input.metadata();
+    let values: Vec<Value> = input.into_iter_strict(span)?.collect();
+
+    let result: Vec<Value> = match arg.take_cell_paths() {
+        None => values
+            .into_par_iter()
+            .map(|v| match v {
+                // Propagate errors inside the input
+                Value::Error { .. } => v,
+                _ => cmd(&v, &arg, span),
+            })
+            .collect(),
+        Some(column_paths) => {
+            let arg = Arc::new(arg);
+            values
+                .into_par_iter()
+                .map(|mut v| {
+                    for path in &column_paths {
+                        let opt = arg.clone();
+                        let r = v.update_cell_path(
+                            &path.members,
+                            Box::new(move |old| {
+                                match old {
+                                    // Propagate errors inside the input
+                                    Value::Error { .. } => old.clone(),
+                                    _ => cmd(old, &opt, span),
+                                }
+                            }),
+                        );
+                        if let Err(error) = r {
+                            return Value::error(error, span);
+                        }
+                    }
+                    v
+                })
+                .collect()
+        }
+    };
+
+    Ok(PipelineData::Value(Value::list(result, span), metadata))
+}
+
+/// Apply `cmd` to a single value, honoring `arg`'s cell paths (if any) the same way the
+/// per-element closures in `operate`/`operate_parallel` do. Used for `operate_parallel`'s
+/// scalar fallback, where there's exactly one value and nothing to parallelize.
+fn apply_one<C, A>(cmd: C, arg: &mut A, mut v: Value, span: Span) -> Value
+where
+    A: CmdArgument,
+    C: Fn(&Value, &A, Span) -> Value,
+{
+    match arg.take_cell_paths() {
+        None => match &v {
+            // Propagate errors inside the input
+            Value::Error { .. } => v,
+            _ => cmd(&v, arg, span),
+        },
+        Some(column_paths) => {
+            for path in &column_paths {
+                let r = v.update_cell_path(
+                    &path.members,
+                    Box::new(|old| match old {
+                        // Propagate errors inside the input
+                        Value::Error { .. } => old.clone(),
+                        _ => cmd(old, arg, span),
+                    }),
+                );
+                if let Err(error) = r {
+                    return Value::error(error, span);
+                }
+            }
+            v
+        }
+    }
+}
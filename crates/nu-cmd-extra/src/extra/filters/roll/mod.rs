@@ -51,36 +51,65 @@ fn horizontal_rotate_value(
     by: &Option<usize>,
     cells_only: bool,
     direction: &HorizontalDirection,
+    except: &[String],
 ) -> Result<Value, ShellError> {
     let span = value.span();
     match value {
         Value::Record {
             val: mut record, ..
         } => {
-            let rotations = by.map(|n| n % record.len()).unwrap_or(1);
+            let rotating_idx: Vec<usize> = (0..record.len())
+                .filter(|i| !except.contains(&record.cols[*i]))
+                .collect();
+
+            if !rotating_idx.is_empty() {
+                let rotations = by.map(|n| n % rotating_idx.len()).unwrap_or(1);
+
+                let mut sub_cols: Vec<String> =
+                    rotating_idx.iter().map(|&i| record.cols[i].clone()).collect();
+                let mut sub_vals: Vec<Value> =
+                    rotating_idx.iter().map(|&i| record.vals[i].clone()).collect();
+
+                if !cells_only {
+                    match direction {
+                        HorizontalDirection::Right => sub_cols.rotate_right(rotations),
+                        HorizontalDirection::Left => sub_cols.rotate_left(rotations),
+                    }
+                }
 
-            if !cells_only {
                 match direction {
-                    HorizontalDirection::Right => record.cols.rotate_right(rotations),
-                    HorizontalDirection::Left => record.cols.rotate_left(rotations),
+                    HorizontalDirection::Right => sub_vals.rotate_right(rotations),
+                    HorizontalDirection::Left => sub_vals.rotate_left(rotations),
                 }
-            };
 
-            match direction {
-                HorizontalDirection::Right => record.vals.rotate_right(rotations),
-                HorizontalDirection::Left => record.vals.rotate_left(rotations),
+                for (pos, &orig_idx) in rotating_idx.iter().enumerate() {
+                    record.cols[orig_idx] = sub_cols[pos].clone();
+                    record.vals[orig_idx] = sub_vals[pos].clone();
+                }
             }
 
             Ok(Value::record(record, span))
         }
-        Value::List { vals, .. } => {
+        Value::List { vals, .. } if vals.is_empty() || vals.iter().all(|v| matches!(v, Value::Record { .. })) => {
             let values = vals
                 .into_iter()
-                .map(|value| horizontal_rotate_value(value, by, cells_only, direction))
+                .map(|value| horizontal_rotate_value(value, by, cells_only, direction, except))
                 .collect::<Result<Vec<Value>, ShellError>>()?;
 
             Ok(Value::list(values, span))
         }
+        Value::List { mut vals, .. } => {
+            // A list of plain values isn't a table, so there are no columns to roll --
+            // instead roll the elements themselves, as though rolling a single row.
+            let rotations = by.map(|n| n % vals.len()).unwrap_or(1);
+
+            match direction {
+                HorizontalDirection::Right => vals.rotate_right(rotations),
+                HorizontalDirection::Left => vals.rotate_left(rotations),
+            }
+
+            Ok(Value::list(vals, span))
+        }
         _ => Err(ShellError::TypeMismatch {
             err_message: "record".to_string(),
             span: value.span(),
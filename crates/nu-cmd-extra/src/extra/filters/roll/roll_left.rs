@@ -25,6 +25,10 @@ impl Command for RollLeft {
             .input_output_types(vec![
                 (Type::Record(vec![]), Type::Record(vec![])),
                 (Type::Table(vec![]), Type::Table(vec![])),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
             ])
             .named(
                 "by",
@@ -37,6 +41,17 @@ impl Command for RollLeft {
                 "rotates columns leaving headers fixed",
                 Some('c'),
             )
+            .named(
+                "except",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "exclude these columns from the rotation, keeping their values in place",
+                Some('e'),
+            )
+            .switch(
+                "reverse",
+                "flip the roll direction, so this behaves like `roll right` (composes with --by)",
+                Some('r'),
+            )
             .category(Category::Filters)
     }
 
@@ -90,6 +105,31 @@ impl Command for RollLeft {
                     Span::test_data(),
                 )),
             },
+            Example {
+                description: "Rolls cells to the left, pinning column 'a' in place",
+                example: "[[a b c]; [1 2 3] [4 5 6]] | roll left --cells-only --except [a]",
+                result: Some(Value::list(
+                    vec![
+                        Value::test_record(Record {
+                            cols: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                            vals: vec![Value::test_int(1), Value::test_int(3), Value::test_int(2)],
+                        }),
+                        Value::test_record(Record {
+                            cols: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                            vals: vec![Value::test_int(4), Value::test_int(6), Value::test_int(5)],
+                        }),
+                    ],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                description: "Rolls columns to the right -- same as `roll right`",
+                example: "{a:1 b:2 c:3} | roll left --reverse",
+                result: Some(Value::test_record(Record {
+                    cols: vec!["c".to_string(), "a".to_string(), "b".to_string()],
+                    vals: vec![Value::test_int(3), Value::test_int(1), Value::test_int(2)],
+                })),
+            },
         ]
     }
 
@@ -101,12 +141,23 @@ impl Command for RollLeft {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let by: Option<usize> = call.get_flag(engine_state, stack, "by")?;
+        let except: Option<Vec<String>> = call.get_flag(engine_state, stack, "except")?;
         let metadata = input.metadata();
 
         let cells_only = call.has_flag("cells-only");
+        let direction = if call.has_flag("reverse") {
+            HorizontalDirection::Right
+        } else {
+            HorizontalDirection::Left
+        };
         let value = input.into_value(call.head);
-        let rotated_value =
-            horizontal_rotate_value(value, &by, cells_only, &HorizontalDirection::Left)?;
+        let rotated_value = horizontal_rotate_value(
+            value,
+            &by,
+            cells_only,
+            &direction,
+            &except.unwrap_or_default(),
+        )?;
 
         Ok(rotated_value.into_pipeline_data().set_metadata(metadata))
     }
@@ -25,6 +25,10 @@ impl Command for RollRight {
             .input_output_types(vec![
                 (Type::Record(vec![]), Type::Record(vec![])),
                 (Type::Table(vec![]), Type::Table(vec![])),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
             ])
             .named(
                 "by",
@@ -37,6 +41,17 @@ impl Command for RollRight {
                 "rotates columns leaving headers fixed",
                 Some('c'),
             )
+            .named(
+                "except",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "exclude these columns from the rotation, keeping their values in place",
+                Some('e'),
+            )
+            .switch(
+                "reverse",
+                "flip the roll direction, so this behaves like `roll left` (composes with --by)",
+                Some('r'),
+            )
             .category(Category::Filters)
     }
 
@@ -90,6 +105,39 @@ impl Command for RollRight {
                     Span::test_data(),
                 )),
             },
+            Example {
+                description: "Rolls cells to the right, pinning column 'a' in place",
+                example: "[[a b c]; [1 2 3] [4 5 6]] | roll right --cells-only --except [a]",
+                result: Some(Value::list(
+                    vec![
+                        Value::test_record(Record {
+                            cols: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                            vals: vec![Value::test_int(1), Value::test_int(3), Value::test_int(2)],
+                        }),
+                        Value::test_record(Record {
+                            cols: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                            vals: vec![Value::test_int(4), Value::test_int(6), Value::test_int(5)],
+                        }),
+                    ],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                description: "Rolls columns to the left -- same as `roll left`",
+                example: "{a:1 b:2 c:3} | roll right --reverse",
+                result: Some(Value::test_record(Record {
+                    cols: vec!["b".to_string(), "c".to_string(), "a".to_string()],
+                    vals: vec![Value::test_int(2), Value::test_int(3), Value::test_int(1)],
+                })),
+            },
+            Example {
+                description: "Rolls the elements of a plain list to the right",
+                example: "[1 2 3] | roll right",
+                result: Some(Value::list(
+                    vec![Value::test_int(3), Value::test_int(1), Value::test_int(2)],
+                    Span::test_data(),
+                )),
+            },
         ]
     }
 
@@ -101,12 +149,23 @@ impl Command for RollRight {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let by: Option<usize> = call.get_flag(engine_state, stack, "by")?;
+        let except: Option<Vec<String>> = call.get_flag(engine_state, stack, "except")?;
         let metadata = input.metadata();
 
         let cells_only = call.has_flag("cells-only");
+        let direction = if call.has_flag("reverse") {
+            HorizontalDirection::Left
+        } else {
+            HorizontalDirection::Right
+        };
         let value = input.into_value(call.head);
-        let rotated_value =
-            horizontal_rotate_value(value, &by, cells_only, &HorizontalDirection::Right)?;
+        let rotated_value = horizontal_rotate_value(
+            value,
+            &by,
+            cells_only,
+            &direction,
+            &except.unwrap_or_default(),
+        )?;
 
         Ok(rotated_value.into_pipeline_data().set_metadata(metadata))
     }
@@ -122,4 +181,97 @@ mod test {
 
         test_examples(RollRight {})
     }
+
+    #[test]
+    fn except_pins_a_column_while_rotating_the_rest() {
+        let span = Span::test_data();
+        let record = Record {
+            cols: vec![
+                "id".to_string(),
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+            ],
+            vals: vec![
+                Value::test_int(0),
+                Value::test_int(1),
+                Value::test_int(2),
+                Value::test_int(3),
+            ],
+        };
+        let value = Value::record(record, span);
+
+        let rotated = horizontal_rotate_value(
+            value,
+            &None,
+            false,
+            &HorizontalDirection::Right,
+            &["id".to_string()],
+        )
+        .expect("record input is valid");
+
+        let Value::Record { val: record, .. } = rotated else {
+            panic!("expected a record");
+        };
+
+        assert_eq!(
+            record.cols,
+            vec![
+                "id".to_string(),
+                "c".to_string(),
+                "a".to_string(),
+                "b".to_string()
+            ]
+        );
+        assert_eq!(
+            record.vals,
+            vec![
+                Value::test_int(0),
+                Value::test_int(3),
+                Value::test_int(1),
+                Value::test_int(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn plain_list_rotates_its_elements_instead_of_erroring() {
+        let span = Span::test_data();
+        let value = Value::list(
+            vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
+            span,
+        );
+
+        let rotated = horizontal_rotate_value(value, &None, false, &HorizontalDirection::Right, &[])
+            .expect("list input is valid");
+
+        assert_eq!(
+            rotated,
+            Value::list(
+                vec![Value::test_int(3), Value::test_int(1), Value::test_int(2)],
+                span
+            )
+        );
+    }
+
+    #[test]
+    fn plain_list_respects_by() {
+        let span = Span::test_data();
+        let value = Value::list(
+            vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
+            span,
+        );
+
+        let rotated =
+            horizontal_rotate_value(value, &Some(2), false, &HorizontalDirection::Right, &[])
+                .expect("list input is valid");
+
+        assert_eq!(
+            rotated,
+            Value::list(
+                vec![Value::test_int(2), Value::test_int(3), Value::test_int(1)],
+                span
+            )
+        );
+    }
 }
@@ -0,0 +1,153 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{IntoPipelineData, PipelineData, ShellError, Span, Spanned, Value};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+/// The unit an inverse trigonometric command should report its result in.
+#[derive(Clone, Copy)]
+pub enum AngleUnit {
+    Radians,
+    Degrees,
+    Gradians,
+    Turns,
+}
+
+impl AngleUnit {
+    pub fn from_radians(self, radians: f64) -> f64 {
+        match self {
+            AngleUnit::Radians => radians,
+            AngleUnit::Degrees => radians.to_degrees(),
+            AngleUnit::Gradians => radians.to_degrees() * 10.0 / 9.0,
+            AngleUnit::Turns => radians / (2.0 * std::f64::consts::PI),
+        }
+    }
+}
+
+/// Resolve the `--degrees`/`--unit` flags shared by the inverse trig commands.
+///
+/// `--degrees` is a shortcut for `--unit degrees` and is rejected alongside `--unit`.
+pub fn angle_unit_from_call(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<AngleUnit, ShellError> {
+    let use_degrees = call.has_flag("degrees");
+    let unit: Option<Spanned<String>> = call.get_flag(engine_state, stack, "unit")?;
+
+    match (use_degrees, unit) {
+        (true, Some(unit)) => Err(ShellError::IncompatibleParameters {
+            left_message: "--degrees".to_string(),
+            left_span: call
+                .get_named_arg("degrees")
+                .map(|named| named.span)
+                .unwrap_or(call.head),
+            right_message: "--unit".to_string(),
+            right_span: unit.span,
+        }),
+        (true, None) => Ok(AngleUnit::Degrees),
+        (false, Some(unit)) => parse_angle_unit(&unit.item, unit.span),
+        (false, None) => Ok(AngleUnit::Radians),
+    }
+}
+
+fn parse_angle_unit(name: &str, span: Span) -> Result<AngleUnit, ShellError> {
+    match name {
+        "radians" => Ok(AngleUnit::Radians),
+        "degrees" => Ok(AngleUnit::Degrees),
+        "gradians" => Ok(AngleUnit::Gradians),
+        "turns" => Ok(AngleUnit::Turns),
+        _ => Err(ShellError::IncorrectValue {
+            msg: "unit must be one of: radians, degrees, gradians, turns".to_string(),
+            val_span: span,
+            call_span: span,
+        }),
+    }
+}
+
+/// What an inverse trig command should do when an element is out of its domain.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OnError {
+    /// Emit an error value for the offending element (current, default behavior).
+    Error,
+    /// Emit `null` for the offending element instead of an error.
+    Null,
+    /// Drop the offending element from the output entirely.
+    Skip,
+}
+
+/// Resolve the `--on-error` flag shared by the inverse trig commands.
+pub fn on_error_from_call(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<OnError, ShellError> {
+    let flag: Option<Spanned<String>> = call.get_flag(engine_state, stack, "on-error")?;
+    match flag {
+        None => Ok(OnError::Error),
+        Some(flag) => match flag.item.as_str() {
+            "error" => Ok(OnError::Error),
+            "null" => Ok(OnError::Null),
+            "skip" => Ok(OnError::Skip),
+            _ => Err(ShellError::IncorrectValue {
+                msg: "on-error must be one of: error, null, skip".to_string(),
+                val_span: flag.span,
+                call_span: flag.span,
+            }),
+        },
+    }
+}
+
+/// Turn a per-element result into what should actually appear in the output, honoring
+/// `on_error`. Returns `None` only for `OnError::Skip` on an error value, meaning the
+/// element should be dropped from the output entirely.
+pub fn apply_on_error(value: Value, on_error: OnError) -> Option<Value> {
+    match (&value, on_error) {
+        (Value::Error { .. }, OnError::Null) => Some(Value::nothing(value.span())),
+        (Value::Error { .. }, OnError::Skip) => None,
+        _ => Some(value),
+    }
+}
+
+/// Map `operate` over `input`, honoring `on_error` the same way regardless of whether `input`
+/// is a list, a range, a stream, or a bare scalar. `PipelineData::map` can only transform
+/// elements 1:1, so `OnError::Skip` -- which needs to *drop* elements -- is handled separately
+/// here via `PipelineData::into_iter` (which, unlike `map`, lets us filter) instead of inside
+/// `map`'s closure.
+pub fn map_with_on_error(
+    input: PipelineData,
+    head: Span,
+    on_error: OnError,
+    ctrlc: Option<Arc<AtomicBool>>,
+    operate: impl Fn(Value) -> Value + Send + Sync + Clone + 'static,
+) -> Result<PipelineData, ShellError> {
+    if on_error != OnError::Skip {
+        return input.map(
+            move |value| apply_on_error(operate(value), on_error).unwrap_or(Value::nothing(head)),
+            ctrlc,
+        );
+    }
+
+    let metadata = input.metadata();
+    let is_list_like = matches!(
+        input,
+        PipelineData::Value(Value::List { .. } | Value::Range { .. }, ..)
+            | PipelineData::ListStream(..)
+            | PipelineData::ExternalStream { .. }
+    );
+
+    let mut survivors = input
+        .into_iter()
+        .filter_map(|value| apply_on_error(operate(value), on_error));
+
+    if is_list_like {
+        let vals: Vec<Value> = survivors.collect();
+        Ok(PipelineData::Value(Value::list(vals, head), metadata))
+    } else {
+        Ok(match survivors.next() {
+            Some(value) => value.into_pipeline_data_with_metadata(metadata),
+            None => PipelineData::Empty,
+        })
+    }
+}
@@ -1,6 +1,14 @@
-use nu_protocol::ast::Call;
+use super::angle_units::{
+    angle_unit_from_call, apply_on_error, map_with_on_error, on_error_from_call, AngleUnit,
+    OnError,
+};
+use nu_engine::CallExt;
+use nu_protocol::ast::{CellPath, Call};
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, Record, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -13,19 +21,59 @@ impl Command for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("math arccos")
             .switch("degrees", "Return degrees instead of radians", Some('d'))
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "Return the result in this unit: radians, degrees, gradians, or turns (conflicts with --degrees)",
+                None,
+            )
+            .switch(
+                "both",
+                "return a record {radians: ..., degrees: ...} per input instead of a single value, so callers don't have to recompute the other unit (conflicts with --degrees and --unit)",
+                None,
+            )
+            .named(
+                "on-error",
+                SyntaxShape::String,
+                "What to do with out-of-domain elements: error (default), null, or skip",
+                None,
+            )
+            .switch(
+                "clamp",
+                "clamp out-of-domain values into [-1, 1] before computing, to paper over floating-point error that pushes a value just past the boundary",
+                None,
+            )
+            .optional(
+                "column",
+                SyntaxShape::CellPath,
+                "for table input, the column to read values from (used together with --into)",
+            )
+            .named(
+                "into",
+                SyntaxShape::String,
+                "store the result in this new column instead of mapping the input in place (requires a column argument)",
+                None,
+            )
             .input_output_types(vec![
                 (Type::Number, Type::Float),
                 (
                     Type::List(Box::new(Type::Number)),
                     Type::List(Box::new(Type::Float)),
                 ),
+                (Type::Table(vec![]), Type::Table(vec![])),
+                // `--both` returns a {radians, degrees} record per input instead.
+                (Type::Number, Type::Record(vec![])),
+                (
+                    Type::List(Box::new(Type::Number)),
+                    Type::List(Box::new(Type::Record(vec![]))),
+                ),
             ])
             .allow_variants_without_examples(true)
             .category(Category::Math)
     }
 
     fn usage(&self) -> &str {
-        "Returns the arccosine of the number."
+        "Returns the arccosine of the number. Accepts --degrees or --unit <radians|degrees|gradians|turns>, --both to get a {radians, degrees} record instead of a single value, --on-error <error|null|skip> for out-of-domain elements, --clamp to tolerate values just outside [-1, 1] from floating-point error, and --into <column> with a column argument to compute into a new column on table input."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -35,19 +83,77 @@ impl Command for SubCommand {
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
-        let use_degrees = call.has_flag("degrees");
+        let both = call.has_flag("both");
+        if both {
+            let both_span = call
+                .get_named_arg("both")
+                .map(|named| named.span)
+                .unwrap_or(head);
+            if let Some(named) = call.get_named_arg("degrees") {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: "--both".to_string(),
+                    left_span: both_span,
+                    right_message: "--degrees".to_string(),
+                    right_span: named.span,
+                });
+            }
+            if let Some(named) = call.get_named_arg("unit") {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: "--both".to_string(),
+                    left_span: both_span,
+                    right_message: "--unit".to_string(),
+                    right_span: named.span,
+                });
+            }
+        }
+        let unit = angle_unit_from_call(engine_state, stack, call)?;
+        let on_error = on_error_from_call(engine_state, stack, call)?;
+        let clamp = call.has_flag("clamp");
         // This doesn't match explicit nulls
         if matches!(input, PipelineData::Empty) {
             return Err(ShellError::PipelineEmpty { dst_span: head });
         }
-        input.map(
-            move |value| operate(value, head, use_degrees),
+
+        let into: Option<Spanned<String>> = call.get_flag(engine_state, stack, "into")?;
+        if let Some(into) = into {
+            let column: CellPath = call.req(engine_state, stack, 0)?;
+
+            // `--on-error skip` drops the whole row, which `PipelineData::map`'s 1:1 shape
+            // can't express, regardless of whether `input` happens to already be a materialized
+            // list -- so collect (via the generic `into_iter`, which handles every input shape,
+            // not just an in-memory list) and filter instead of mapping in place.
+            if on_error == OnError::Skip {
+                let rows: Vec<Value> = input
+                    .into_iter()
+                    .filter_map(|row| {
+                        insert_arccos_column(row, &column, &into.item, head, unit, on_error, clamp, both)
+                    })
+                    .collect();
+                return Ok(Value::list(rows, head).into_pipeline_data());
+            }
+
+            return input.map(
+                move |row| {
+                    insert_arccos_column(
+                        row, &column, &into.item, head, unit, on_error, clamp, both,
+                    )
+                    .unwrap_or_else(|| Value::nothing(head))
+                },
+                engine_state.ctrlc.clone(),
+            );
+        }
+
+        map_with_on_error(
+            input,
+            head,
+            on_error,
             engine_state.ctrlc.clone(),
+            move |value| operate(value, head, unit, clamp, both),
         )
     }
 
@@ -63,11 +169,41 @@ impl Command for SubCommand {
                 example: "-1 | math arccos -d",
                 result: Some(Value::test_float(180.0)),
             },
+            Example {
+                description: "Compute the arccosine of a table column into a new column, leaving the original intact",
+                example: "[[value]; [1] [-1]] | math arccos value --into angle",
+                result: None,
+            },
+            Example {
+                description: "Get both the radians and degrees of the arccosine of -1 at once",
+                example: "-1 | math arccos --both",
+                result: Some(Value::test_record(Record {
+                    cols: vec!["radians".to_string(), "degrees".to_string()],
+                    vals: vec![
+                        Value::test_float(std::f64::consts::PI),
+                        Value::test_float(180.0),
+                    ],
+                })),
+            },
         ]
     }
 }
 
-fn operate(value: Value, head: Span, use_degrees: bool) -> Value {
+/// Build the `--both` result: a record with the angle in both radians and degrees.
+fn both_record(radians: f64, span: Span) -> Value {
+    Value::record(
+        Record {
+            cols: vec!["radians".to_string(), "degrees".to_string()],
+            vals: vec![
+                Value::float(radians, span),
+                Value::float(radians.to_degrees(), span),
+            ],
+        },
+        span,
+    )
+}
+
+fn operate(value: Value, head: Span, unit: AngleUnit, clamp: bool, both: bool) -> Value {
     match value {
         numeric @ (Value::Int { .. } | Value::Float { .. }) => {
             let span = numeric.span();
@@ -77,17 +213,47 @@ fn operate(value: Value, head: Span, use_degrees: bool) -> Value {
                 _ => unreachable!(),
             };
 
-            if (-1.0..=1.0).contains(&val) {
-                let val = val.acos();
-                let val = if use_degrees { val.to_degrees() } else { val };
+            if val.is_nan() {
+                // NaN falls outside `(-1.0..=1.0).contains(..)` too, but reporting it through
+                // the generic out-of-domain message below would be misleading (there's no
+                // "interval" to clamp into, and --clamp can't help). Call it out explicitly.
+                Value::error(
+                    ShellError::UnsupportedInput(
+                        "'arccos' is undefined for NaN".into(),
+                        "value originates from here".into(),
+                        span,
+                        span,
+                    ),
+                    span,
+                )
+            } else if (-1.0..=1.0).contains(&val) {
+                let radians = val.acos();
+
+                if both {
+                    both_record(radians, span)
+                } else {
+                    Value::float(unit.from_radians(radians), span)
+                }
+            } else if clamp {
+                let radians = val.clamp(-1.0, 1.0).acos();
 
-                Value::float(val, span)
+                if both {
+                    both_record(radians, span)
+                } else {
+                    Value::float(unit.from_radians(radians), span)
+                }
             } else {
+                // Both labels point at the originating element's own span (not `head`), so
+                // that an out-of-domain element inside a list is flagged at its own position.
                 Value::error(
                     ShellError::UnsupportedInput(
-                        "'arccos' undefined for values outside the closed interval [-1, 1].".into(),
+                        "'arccos' is undefined for values outside the closed interval [-1, 1]; \
+                         nushell doesn't support complex results. If this value should be exactly \
+                         -1 or 1 and only missed the boundary due to floating-point error, pass \
+                         --clamp to clamp it into range before computing."
+                            .into(),
                         "value originates from here".into(),
-                        head,
+                        span,
                         span,
                     ),
                     span,
@@ -107,6 +273,51 @@ fn operate(value: Value, head: Span, use_degrees: bool) -> Value {
     }
 }
 
+/// Read `column` out of `row`, take its arccosine, and store the result under `into` on a
+/// clone of `row` -- leaving the original `column` untouched. `row` must be a record; any
+/// other input type errors, matching the unmapped path's `OnlySupportsThisInputType`. Returns
+/// `None` only when `on_error` is [`OnError::Skip`] and the value is out of domain, so the
+/// caller can drop the whole row instead of inserting a `null`.
+#[allow(clippy::too_many_arguments)]
+fn insert_arccos_column(
+    row: Value,
+    column: &CellPath,
+    into: &str,
+    head: Span,
+    unit: AngleUnit,
+    on_error: OnError,
+    clamp: bool,
+    both: bool,
+) -> Option<Value> {
+    let Value::Record { val: record, .. } = &row else {
+        return Some(Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "table".into(),
+                wrong_type: row.get_type().to_string(),
+                dst_span: head,
+                src_span: row.span(),
+            },
+            head,
+        ));
+    };
+
+    let span = row.span();
+    let value = match row.clone().follow_cell_path(&column.members, false) {
+        Ok(value) => value,
+        Err(error) => return Some(Value::error(error, span)),
+    };
+
+    let result = apply_on_error(operate(value, head, unit, clamp, both), on_error)?;
+
+    let mut record = record.clone();
+    match record.cols.iter().position(|col| col == into) {
+        Some(index) => record.vals[index] = result,
+        None => record.push(into, result),
+    }
+
+    Some(Value::record(record, span))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -117,4 +328,203 @@ mod test {
 
         test_examples(SubCommand {})
     }
+
+    #[test]
+    fn test_into_column_leaves_source_column_intact() {
+        use nu_protocol::ast::PathMember;
+
+        let span = Span::test_data();
+        let column = CellPath {
+            members: vec![PathMember::String {
+                val: "value".to_string(),
+                span,
+                optional: false,
+            }],
+        };
+        let row = Value::test_record(Record {
+            cols: vec!["value".to_string()],
+            vals: vec![Value::test_float(1.0)],
+        });
+
+        let result = insert_arccos_column(row, &column, "angle", span, AngleUnit::Radians, OnError::Error, false, false)
+            .expect("not skipped");
+
+        match result {
+            Value::Record { val: record, .. } => {
+                assert_eq!(record.cols, vec!["value".to_string(), "angle".to_string()]);
+                assert!(matches!(record.vals[0], Value::Float { val, .. } if val == 1.0));
+                assert!(matches!(record.vals[1], Value::Float { val, .. } if val == 0.0));
+            }
+            other => panic!("expected a record, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_gradians() {
+        let span = Span::test_data();
+        let actual = operate(Value::test_float(-1.0), span, AngleUnit::Gradians, false, false);
+        match actual {
+            Value::Float { val, .. } => assert!((val - 200.0).abs() < 1e-9),
+            other => panic!("expected a float, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_on_error_policies_over_a_mixed_list() {
+        let span = Span::test_data();
+        let values = [Value::test_float(1.0), Value::test_float(2.0)];
+
+        let errored: Vec<Value> = values
+            .iter()
+            .cloned()
+            .map(|v| operate(v, span, AngleUnit::Radians, false, false))
+            .collect();
+        assert!(matches!(errored[0], Value::Float { .. }));
+        assert!(matches!(errored[1], Value::Error { .. }));
+
+        let nulled: Vec<Option<Value>> = values
+            .iter()
+            .cloned()
+            .map(|v| apply_on_error(operate(v, span, AngleUnit::Radians, false, false), OnError::Null))
+            .collect();
+        assert!(matches!(nulled[0], Some(Value::Float { .. })));
+        assert!(matches!(nulled[1], Some(Value::Nothing { .. })));
+
+        let skipped: Vec<Value> = values
+            .iter()
+            .cloned()
+            .filter_map(|v| apply_on_error(operate(v, span, AngleUnit::Radians, false, false), OnError::Skip))
+            .collect();
+        assert_eq!(skipped.len(), 1);
+        assert!(matches!(skipped[0], Value::Float { .. }));
+    }
+
+    #[test]
+    fn test_out_of_domain_error_points_at_its_own_element_not_call_head() {
+        let head = Span::new(0, 20);
+        let first_span = Span::new(21, 25);
+        let second_span = Span::new(26, 30);
+        let values = [
+            Value::float(0.5, first_span),
+            Value::float(2.0, second_span),
+        ];
+
+        let results: Vec<Value> = values
+            .into_iter()
+            .map(|v| operate(v, head, AngleUnit::Radians, false, false))
+            .collect();
+
+        assert!(matches!(results[0], Value::Float { .. }));
+        match &results[1] {
+            Value::Error { error, .. } => {
+                if let ShellError::UnsupportedInput(_, _, dst_span, src_span) = error.as_ref() {
+                    assert_eq!(*dst_span, second_span);
+                    assert_eq!(*src_span, second_span);
+                    assert_ne!(*dst_span, head);
+                } else {
+                    panic!("expected UnsupportedInput, got {error:?}");
+                }
+            }
+            other => panic!("expected an error value, got {other:?}"),
+        }
+        assert_eq!(results[1].span(), second_span);
+    }
+
+    #[test]
+    fn test_out_of_domain_error_mentions_complex_and_clamp() {
+        let span = Span::test_data();
+        let result = operate(Value::test_float(2.0), span, AngleUnit::Radians, false, false);
+
+        match result {
+            Value::Error { error, .. } => {
+                if let ShellError::UnsupportedInput(msg, _, _, _) = error.as_ref() {
+                    assert!(msg.contains("complex"));
+                    assert!(msg.contains("--clamp"));
+                } else {
+                    panic!("expected UnsupportedInput, got {error:?}");
+                }
+            }
+            other => panic!("expected an error value, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_nan_input_gets_a_nan_specific_message_instead_of_the_interval_one() {
+        let span = Span::test_data();
+        let result = operate(Value::test_float(f64::NAN), span, AngleUnit::Radians, false, false);
+
+        match result {
+            Value::Error { error, .. } => {
+                if let ShellError::UnsupportedInput(msg, _, _, _) = error.as_ref() {
+                    assert!(msg.contains("NaN"));
+                    assert!(!msg.contains("interval"));
+                } else {
+                    panic!("expected UnsupportedInput, got {error:?}");
+                }
+            }
+            other => panic!("expected an error value, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_nan_input_is_still_affected_by_on_error_null() {
+        let span = Span::test_data();
+        let result = operate(Value::test_float(f64::NAN), span, AngleUnit::Radians, false, false);
+        let nulled = apply_on_error(result, OnError::Null);
+
+        assert!(matches!(nulled, Some(Value::Nothing { .. })));
+    }
+
+    #[test]
+    fn test_clamp_tolerates_values_just_outside_the_domain() {
+        let span = Span::test_data();
+        let result = operate(Value::test_float(1.0000001), span, AngleUnit::Radians, true, false);
+
+        match result {
+            Value::Float { val, .. } => assert!((val - 0.0).abs() < 1e-3),
+            other => panic!("expected a float, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_both_returns_a_record_with_radians_and_degrees_for_a_scalar() {
+        let span = Span::test_data();
+        let result = operate(Value::test_float(-1.0), span, AngleUnit::Radians, false, true);
+
+        match result {
+            Value::Record { val: record, .. } => {
+                assert_eq!(record.cols, vec!["radians".to_string(), "degrees".to_string()]);
+                assert!(
+                    matches!(record.vals[0], Value::Float { val, .. } if (val - std::f64::consts::PI).abs() < 1e-9)
+                );
+                assert!(
+                    matches!(record.vals[1], Value::Float { val, .. } if (val - 180.0).abs() < 1e-9)
+                );
+            }
+            other => panic!("expected a record, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_both_returns_a_record_per_element_for_a_list() {
+        let span = Span::test_data();
+        let values = [Value::test_float(1.0), Value::test_float(-1.0)];
+
+        let results: Vec<Value> = values
+            .iter()
+            .cloned()
+            .map(|v| operate(v, span, AngleUnit::Radians, false, true))
+            .collect();
+
+        for result in &results {
+            assert!(matches!(result, Value::Record { .. }));
+        }
+        match &results[0] {
+            Value::Record { val: record, .. } => {
+                assert!(matches!(record.vals[0], Value::Float { val, .. } if val == 0.0));
+                assert!(matches!(record.vals[1], Value::Float { val, .. } if val == 0.0));
+            }
+            other => panic!("expected a record, got {other:?}"),
+        }
+    }
 }
@@ -1,6 +1,12 @@
+use super::angle_units::{
+    angle_unit_from_call, apply_on_error, map_with_on_error, on_error_from_call, AngleUnit,
+    OnError,
+};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -13,6 +19,18 @@ impl Command for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("math arctan")
             .switch("degrees", "Return degrees instead of radians", Some('d'))
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "Return the result in this unit: radians, degrees, gradians, or turns (conflicts with --degrees)",
+                None,
+            )
+            .named(
+                "on-error",
+                SyntaxShape::String,
+                "What to do with out-of-domain elements: error (default), null, or skip (arctan has no domain restriction, so this only affects propagated errors)",
+                None,
+            )
             .input_output_types(vec![
                 (Type::Number, Type::Float),
                 (
@@ -25,7 +43,7 @@ impl Command for SubCommand {
     }
 
     fn usage(&self) -> &str {
-        "Returns the arctangent of the number."
+        "Returns the arctangent of the number. Accepts --degrees or --unit <radians|degrees|gradians|turns>, and --on-error <error|null|skip> for propagated errors."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -35,19 +53,24 @@ impl Command for SubCommand {
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
-        let use_degrees = call.has_flag("degrees");
+        let unit = angle_unit_from_call(engine_state, stack, call)?;
+        let on_error = on_error_from_call(engine_state, stack, call)?;
         // This doesn't match explicit nulls
         if matches!(input, PipelineData::Empty) {
             return Err(ShellError::PipelineEmpty { dst_span: head });
         }
-        input.map(
-            move |value| operate(value, head, use_degrees),
+
+        map_with_on_error(
+            input,
+            head,
+            on_error,
             engine_state.ctrlc.clone(),
+            move |value| operate(value, head, unit),
         )
     }
 
@@ -68,7 +91,7 @@ impl Command for SubCommand {
     }
 }
 
-fn operate(value: Value, head: Span, use_degrees: bool) -> Value {
+fn operate(value: Value, head: Span, unit: AngleUnit) -> Value {
     match value {
         numeric @ (Value::Int { .. } | Value::Float { .. }) => {
             let span = numeric.span();
@@ -78,8 +101,7 @@ fn operate(value: Value, head: Span, use_degrees: bool) -> Value {
                 _ => unreachable!(),
             };
 
-            let val = val.atan();
-            let val = if use_degrees { val.to_degrees() } else { val };
+            let val = unit.from_radians(val.atan());
 
             Value::float(val, span)
         }
@@ -106,4 +128,18 @@ mod test {
 
         test_examples(SubCommand {})
     }
+
+    #[test]
+    fn test_on_error_is_a_no_op_since_arctan_has_no_domain_restriction() {
+        let span = Span::test_data();
+        let values = [Value::test_float(1.0), Value::test_float(1000.0)];
+
+        for on_error in [OnError::Error, OnError::Null, OnError::Skip] {
+            for value in &values {
+                let result =
+                    apply_on_error(operate(value.clone(), span, AngleUnit::Radians), on_error);
+                assert!(matches!(result, Some(Value::Float { .. })));
+            }
+        }
+    }
 }
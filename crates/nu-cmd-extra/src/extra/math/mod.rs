@@ -1,3 +1,4 @@
+mod angle_units;
 mod cos;
 mod cosh;
 mod sin;
@@ -0,0 +1,31 @@
+use nu_test_support::nu;
+
+#[test]
+fn arccos_on_error_skip_drops_out_of_domain_values_from_a_range() {
+    let actual = nu!("1.0..2.0 | math arccos --on-error skip | length");
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn arcsin_on_error_skip_drops_out_of_domain_values_from_a_range() {
+    let actual = nu!("1.0..2.0 | math arcsin --on-error skip | length");
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn arctan_on_error_skip_keeps_a_scalar_input_a_scalar_output() {
+    let actual = nu!("1.5 | math arctan --on-error skip | describe");
+
+    assert_eq!(actual.out, "float");
+}
+
+#[test]
+fn arccos_on_error_skip_drops_out_of_domain_rows_via_into() {
+    let actual = nu!(
+        "[[value]; [0.5] [2.0]] | math arccos value --into angle --on-error skip | length"
+    );
+
+    assert_eq!(actual.out, "1");
+}
@@ -1,2 +1,6 @@
 #[cfg(feature = "extra")]
 mod bytes;
+#[cfg(feature = "extra")]
+mod math;
+#[cfg(feature = "extra")]
+mod roll;
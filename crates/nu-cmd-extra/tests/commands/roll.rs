@@ -0,0 +1,25 @@
+use nu_test_support::nu;
+
+#[test]
+fn roll_right_reverse_matches_roll_left() {
+    let reversed = nu!(r#"{a:1 b:2 c:3} | roll right --reverse | to json -r"#);
+    let left = nu!(r#"{a:1 b:2 c:3} | roll left | to json -r"#);
+
+    assert_eq!(reversed.out, left.out);
+}
+
+#[test]
+fn roll_left_reverse_matches_roll_right() {
+    let reversed = nu!(r#"{a:1 b:2 c:3} | roll left --reverse | to json -r"#);
+    let right = nu!(r#"{a:1 b:2 c:3} | roll right | to json -r"#);
+
+    assert_eq!(reversed.out, right.out);
+}
+
+#[test]
+fn roll_right_reverse_composes_with_by() {
+    let reversed = nu!(r#"{a:1 b:2 c:3} | roll right --reverse --by 2 | to json -r"#);
+    let left = nu!(r#"{a:1 b:2 c:3} | roll left --by 2 | to json -r"#);
+
+    assert_eq!(reversed.out, left.out);
+}
@@ -1,17 +1,27 @@
 use nu_cmd_base::input_handler::{operate, CmdArgument};
-use nu_engine::CallExt;
+use nu_engine::{current_dir, eval_block, CallExt};
 use nu_protocol::{
     ast::{Call, CellPath},
-    engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, Record, ShellError, Signature, Span, Spanned, SyntaxShape,
-    Type, Value,
+    engine::{Block, Closure, Command, EngineState, EnvVars, Stack},
+    Category, Example, IntoPipelineData, PipelineData, Record, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
 };
+use std::collections::HashSet;
 
 struct Arguments {
-    find: Vec<u8>,
-    replace: Vec<u8>,
+    patterns: Vec<Pattern>,
     cell_paths: Option<Vec<CellPath>>,
     all: bool,
+    ignore_case: bool,
+    output_binary: bool,
+    max_size: Option<i64>,
+}
+
+/// One find/replace pair. In `--multiple` mode there can be several, scanned left-to-right
+/// at each position with the first one listed that matches winning.
+struct Pattern {
+    find: Vec<u8>,
+    replace: Vec<u8>,
 }
 
 impl CmdArgument for Arguments {
@@ -32,23 +42,75 @@ impl Command for BytesReplace {
         Signature::build("bytes replace")
             .input_output_types(vec![
                 (Type::Binary, Type::Binary),
+                (Type::String, Type::Any),
+                (
+                    Type::List(Box::new(Type::Binary)),
+                    Type::List(Box::new(Type::Binary)),
+                ),
                 (Type::Table(vec![]), Type::Table(vec![])),
                 (Type::Record(vec![]), Type::Record(vec![])),
             ])
             .allow_variants_without_examples(true)
-            .required("find", SyntaxShape::Binary, "the pattern to find")
-            .required("replace", SyntaxShape::Binary, "the replacement pattern")
+            .optional(
+                "find",
+                SyntaxShape::Any,
+                "the pattern to find, or (with --multiple) a list of patterns; omit if using --from-file",
+            )
+            .optional(
+                "replace",
+                SyntaxShape::Any,
+                "the replacement pattern, or (with --multiple) a list of replacements; omit if using --to-file",
+            )
+            .named(
+                "from-file",
+                SyntaxShape::Filepath,
+                "read the find pattern bytes from this file instead of the `find` argument",
+                None,
+            )
+            .named(
+                "to-file",
+                SyntaxShape::Filepath,
+                "read the replacement bytes from this file instead of the `replace` argument",
+                None,
+            )
+            .named(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Binary])),
+                "compute the replacement per match by calling this closure with the matched bytes; it must return binary (conflicts with `replace`, --to-file, and --multiple)",
+                None,
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
                 "for a data structure input, replace bytes in data at the given cell paths",
             )
             .switch("all", "replace all occurrences of find binary", Some('a'))
+            .switch(
+                "ignore-case",
+                "match ASCII case-insensitively, treating non-ASCII bytes as exact",
+                Some('i'),
+            )
+            .switch(
+                "multiple",
+                "treat find and replace as equal-length lists of patterns, applied in a single left-to-right scan (first matching pattern in the list wins at each position)",
+                Some('m'),
+            )
+            .switch(
+                "output-binary",
+                "always return binary, even when the input was a string and the result is valid UTF-8",
+                None,
+            )
+            .named(
+                "max-size",
+                SyntaxShape::Filesize,
+                "abort with an error if the replaced output would grow past this size, guarding against a blowup when --all is combined with a much longer replace pattern (default: unlimited)",
+                None,
+            )
             .category(Category::Bytes)
     }
 
     fn usage(&self) -> &str {
-        "Find and replace binary."
+        "Find and replace binary. With `--ignore-case`, ASCII letters are matched case-insensitively while non-ASCII bytes must match exactly. With `--multiple`, apply several find/replace pairs in one pass. String input is treated as its UTF-8 bytes; the result is returned as a string when it's still valid UTF-8, unless `--output-binary` forces binary output."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -64,19 +126,160 @@ impl Command for BytesReplace {
     ) -> Result<PipelineData, ShellError> {
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 2)?;
         let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
-        let find = call.req::<Spanned<Vec<u8>>>(engine_state, stack, 0)?;
-        if find.item.is_empty() {
-            return Err(ShellError::TypeMismatch {
-                err_message: "the pattern to find cannot be empty".to_string(),
-                span: find.span,
+
+        let find_arg: Option<Value> = call.opt(engine_state, stack, 0)?;
+        let replace_arg: Option<Value> = call.opt(engine_state, stack, 1)?;
+        let from_file: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "from-file")?;
+        let to_file: Option<Spanned<String>> = call.get_flag(engine_state, stack, "to-file")?;
+        let closure: Option<Closure> = call.get_flag(engine_state, stack, "closure")?;
+        let max_size: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "max-size")?;
+        if let Some(max_size) = &max_size {
+            if max_size.item < 0 {
+                return Err(ShellError::TypeMismatch {
+                    err_message: "max-size cannot be negative".to_string(),
+                    span: max_size.span,
+                });
+            }
+        }
+        let max_size = max_size.map(|max_size| max_size.item);
+
+        if let Some(closure) = closure {
+            if let Some(conflict) = replace_arg
+                .as_ref()
+                .map(|v| ("replace", v.span()))
+                .or(to_file.as_ref().map(|f| ("--to-file", f.span)))
+            {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: "--closure".to_string(),
+                    left_span: call.head,
+                    right_message: conflict.0.to_string(),
+                    right_span: conflict.1,
+                });
+            }
+            if call.has_flag("multiple") {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: "--closure".to_string(),
+                    left_span: call.head,
+                    right_message: "--multiple".to_string(),
+                    right_span: call.head,
+                });
+            }
+
+            let find_bytes = match from_file {
+                Some(path) => read_pattern_file(engine_state, stack, &path)?,
+                None => {
+                    let find_arg = require_arg(find_arg, "find", call.head)?;
+                    let find_span = find_arg.span();
+                    let find_bytes = find_arg.as_binary()?.to_vec();
+                    if find_bytes.is_empty() {
+                        return Err(ShellError::TypeMismatch {
+                            err_message: "the pattern to find cannot be empty".to_string(),
+                            span: find_span,
+                        });
+                    }
+                    find_bytes
+                }
+            };
+
+            return replace_with_closure_over_input(
+                engine_state,
+                stack,
+                closure,
+                find_bytes,
+                cell_paths,
+                call.has_flag("all"),
+                call.has_flag("ignore-case"),
+                call.has_flag("output-binary"),
+                max_size,
+                call.head,
+                input,
+            );
+        }
+
+        if call.has_flag("multiple") && (from_file.is_some() || to_file.is_some()) {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--multiple".to_string(),
+                left_span: call.head,
+                right_message: "--from-file/--to-file".to_string(),
+                right_span: from_file
+                    .as_ref()
+                    .or(to_file.as_ref())
+                    .map(|f| f.span)
+                    .unwrap_or(call.head),
             });
         }
 
+        let patterns = if call.has_flag("multiple") {
+            let find_arg = require_arg(find_arg, "find", call.head)?;
+            let replace_arg = require_arg(replace_arg, "replace", call.head)?;
+            let finds = find_arg.as_list()?;
+            let replaces = replace_arg.as_list()?;
+            if finds.len() != replaces.len() {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: format!("{} find patterns", finds.len()),
+                    left_span: find_arg.span(),
+                    right_message: format!("{} replace patterns", replaces.len()),
+                    right_span: replace_arg.span(),
+                });
+            }
+
+            finds
+                .iter()
+                .zip(replaces.iter())
+                .map(|(find_item, replace_item)| -> Result<Pattern, ShellError> {
+                    let find_span = find_item.span();
+                    let find = find_item.as_binary()?.to_vec();
+                    if find.is_empty() {
+                        return Err(ShellError::TypeMismatch {
+                            err_message: "the pattern to find cannot be empty".to_string(),
+                            span: find_span,
+                        });
+                    }
+                    Ok(Pattern {
+                        find,
+                        replace: replace_item.as_binary()?.to_vec(),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            let find_bytes = match from_file {
+                Some(path) => read_pattern_file(engine_state, stack, &path)?,
+                None => {
+                    let find_arg = require_arg(find_arg, "find", call.head)?;
+                    let find_span = find_arg.span();
+                    let find_bytes = find_arg.as_binary()?.to_vec();
+                    if find_bytes.is_empty() {
+                        return Err(ShellError::TypeMismatch {
+                            err_message: "the pattern to find cannot be empty".to_string(),
+                            span: find_span,
+                        });
+                    }
+                    find_bytes
+                }
+            };
+
+            let replace_bytes = match to_file {
+                Some(path) => read_pattern_file(engine_state, stack, &path)?,
+                None => {
+                    let replace_arg = require_arg(replace_arg, "replace", call.head)?;
+                    replace_arg.as_binary()?.to_vec()
+                }
+            };
+
+            vec![Pattern {
+                find: find_bytes,
+                replace: replace_bytes,
+            }]
+        };
+
         let arg = Arguments {
-            find: find.item,
-            replace: call.req::<Vec<u8>>(engine_state, stack, 1)?,
+            patterns,
             cell_paths,
             all: call.has_flag("all"),
+            ignore_case: call.has_flag("ignore-case"),
+            output_binary: call.has_flag("output-binary"),
+            max_size,
         };
 
         operate(replace, arg, input, call.head, engine_state.ctrlc.clone())
@@ -124,19 +327,273 @@ impl Command for BytesReplace {
                     Span::test_data(),
                 )),
             },
+            Example {
+                description: "Find and replace in each binary of a list",
+                example: "[0x[10 AA] 0x[10 BB]] | bytes replace 0x[10] 0x[FF]",
+                result: Some(Value::list(
+                    vec![
+                        Value::binary(vec![0xFF, 0xAA], Span::test_data()),
+                        Value::binary(vec![0xFF, 0xBB], Span::test_data()),
+                    ],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                description: "Apply several substitutions in a single left-to-right scan",
+                example: "'abcabc' | into binary | bytes replace --all --multiple [0x[61] 0x[62]] [0x[31] 0x[32]]",
+                result: Some(Value::binary(
+                    vec![0x31, 0x32, 0x63, 0x31, 0x32, 0x63],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                description: "Replace in a string input, getting a string back since the result is still valid UTF-8",
+                example: "'foo bar' | bytes replace 'bar' 'baz'",
+                result: Some(Value::test_string("foo baz")),
+            },
+            Example {
+                description: "Force binary output from a string input",
+                example: "'foo bar' | bytes replace --output-binary 'bar' 'baz'",
+                result: Some(Value::binary(b"foo baz".to_vec(), Span::test_data())),
+            },
+            Example {
+                description: "Read the find and replace patterns from files, for large binary patches",
+                example: "0x[10 AA FF] | bytes replace --from-file find.bin --to-file replace.bin",
+                result: None,
+            },
+            Example {
+                description: "Compute each replacement from the matched bytes with a closure",
+                example: "0x[AA BB AA] | bytes replace --all --closure {|match| $match | bytes reverse} 0x[AA BB]",
+                result: None,
+            },
+            Example {
+                description: "Guard against a runaway output size when --all meets a much longer replacement",
+                example: "0x[AA AA AA] | bytes replace --all --max-size 5b 0x[AA] 0x[AA AA AA AA AA AA AA AA AA AA]",
+                result: None,
+            },
         ]
     }
 }
 
+fn require_arg(arg: Option<Value>, name: &str, span: Span) -> Result<Value, ShellError> {
+    arg.ok_or_else(|| ShellError::MissingParameter {
+        param_name: name.to_string(),
+        span,
+    })
+}
+
+/// Read a find/replace pattern's raw bytes from a file, resolving `path` relative to the
+/// current directory. Large binary patterns are unwieldy as hex literals, so `--from-file` and
+/// `--to-file` let callers point at a file instead.
+fn read_pattern_file(
+    engine_state: &EngineState,
+    stack: &Stack,
+    path: &Spanned<String>,
+) -> Result<Vec<u8>, ShellError> {
+    let cwd = current_dir(engine_state, stack)?;
+    let full_path = cwd.join(&path.item);
+    std::fs::read(&full_path).map_err(|err| {
+        ShellError::GenericError(
+            format!("could not read pattern file '{}'", path.item),
+            err.to_string(),
+            Some(path.span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+/// Build the error returned when accumulated output crosses `--max-size`, guarding against an
+/// unbounded blowup when `--all` pairs a short `find` with a much longer `replace`.
+fn max_size_exceeded_error(max_size: i64, span: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("replaced output would exceed the --max-size limit of {max_size} bytes"),
+        "use a smaller --all replacement, or raise or drop --max-size".to_string(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+/// Drive `--closure` mode: evaluate `closure` once per match of `find` against the input
+/// (or, with `cell_paths`, once per match within each named cell path), replacing each match
+/// with the closure's return value. The closure must return binary.
+#[allow(clippy::too_many_arguments)]
+fn replace_with_closure_over_input(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    closure: Closure,
+    find: Vec<u8>,
+    cell_paths: Option<Vec<CellPath>>,
+    all: bool,
+    ignore_case: bool,
+    output_binary: bool,
+    max_size: Option<i64>,
+    head: Span,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let block = engine_state.get_block(closure.block_id).clone();
+    let mut closure_stack = stack.captures_to_stack(&closure.captures);
+    let orig_env_vars = closure_stack.env_vars.clone();
+    let orig_env_hidden = closure_stack.env_hidden.clone();
+    let engine_state = engine_state.clone();
+    let ctrlc = engine_state.ctrlc.clone();
+
+    Ok(input.map(
+        move |mut val| match &cell_paths {
+            Some(cell_paths) => {
+                for path in cell_paths {
+                    let find = find.clone();
+                    let result = val.update_cell_path(
+                        &path.members,
+                        Box::new(|old| {
+                            replace_with_closure(
+                                old,
+                                &find,
+                                &engine_state,
+                                &mut closure_stack,
+                                &orig_env_vars,
+                                &orig_env_hidden,
+                                &block,
+                                all,
+                                ignore_case,
+                                output_binary,
+                                max_size,
+                                head,
+                            )
+                        }),
+                    );
+                    if let Err(e) = result {
+                        return Value::error(e, head);
+                    }
+                }
+                val
+            }
+            None => replace_with_closure(
+                &val,
+                &find,
+                &engine_state,
+                &mut closure_stack,
+                &orig_env_vars,
+                &orig_env_hidden,
+                &block,
+                all,
+                ignore_case,
+                output_binary,
+                max_size,
+                head,
+            ),
+        },
+        ctrlc,
+    ))
+}
+
+/// Evaluate `block` once per match of `find` in `val`'s bytes, replacing each match with the
+/// block's output (which must be binary).
+#[allow(clippy::too_many_arguments)]
+fn replace_with_closure(
+    val: &Value,
+    find: &[u8],
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    orig_env_vars: &[EnvVars],
+    orig_env_hidden: &std::collections::HashMap<String, HashSet<String>>,
+    block: &Block,
+    all: bool,
+    ignore_case: bool,
+    output_binary: bool,
+    max_size: Option<i64>,
+    head: Span,
+) -> Value {
+    let val_span = val.span();
+    let (input_bytes, from_string): (Vec<u8>, bool) = match val {
+        Value::Binary { val, .. } => (val.clone(), false),
+        Value::String { val, .. } => (val.as_bytes().to_vec(), true),
+        Value::Error { .. } => return val.clone(),
+        other => {
+            return Value::error(
+                ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "binary or string".into(),
+                    wrong_type: other.get_type().to_string(),
+                    dst_span: head,
+                    src_span: other.span(),
+                },
+                head,
+            )
+        }
+    };
+
+    let mut replaced = Vec::new();
+    let input_len = input_bytes.len();
+    let mut pos = 0;
+    while pos < input_len {
+        let end = pos + find.len();
+        if end <= input_len && bytes_eq(&input_bytes[pos..end], find, ignore_case) {
+            let matched = input_bytes[pos..end].to_vec();
+            stack.with_env(orig_env_vars, orig_env_hidden);
+            if let Some(var) = block.signature.get_positional(0) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, Value::binary(matched.clone(), val_span));
+                }
+            }
+
+            let output = eval_block(
+                engine_state,
+                stack,
+                block,
+                Value::binary(matched, val_span).into_pipeline_data(),
+                false,
+                false,
+            );
+            let output = match output {
+                Ok(pd) => pd.into_value(head),
+                Err(e) => return Value::error(e, head),
+            };
+            match output.as_binary() {
+                Ok(bytes) => replaced.extend_from_slice(bytes),
+                Err(e) => return Value::error(e, head),
+            }
+            if let Some(max_size) = max_size {
+                if replaced.len() as i64 > max_size {
+                    return Value::error(max_size_exceeded_error(max_size, head), head);
+                }
+            }
+
+            pos = end;
+            if !all {
+                break;
+            }
+        } else {
+            replaced.push(input_bytes[pos]);
+            pos += 1;
+        }
+    }
+
+    replaced.extend_from_slice(&input_bytes[pos..]);
+    if let Some(max_size) = max_size {
+        if replaced.len() as i64 > max_size {
+            return Value::error(max_size_exceeded_error(max_size, head), head);
+        }
+    }
+
+    if from_string && !output_binary {
+        if let Ok(s) = String::from_utf8(replaced.clone()) {
+            return Value::string(s, val_span);
+        }
+    }
+    Value::binary(replaced, val_span)
+}
+
 fn replace(val: &Value, args: &Arguments, span: Span) -> Value {
     let val_span = val.span();
     match val {
-        Value::Binary { val, .. } => replace_impl(val, args, val_span),
+        Value::Binary { val, .. } => replace_impl(val, args, val_span, false),
+        Value::String { val, .. } => replace_impl(val.as_bytes(), args, val_span, true),
         // Propagate errors by explicitly matching them before the final case.
         Value::Error { .. } => val.clone(),
         other => Value::error(
             ShellError::OnlySupportsThisInputType {
-                exp_input_type: "binary".into(),
+                exp_input_type: "binary or string".into(),
                 wrong_type: other.get_type().to_string(),
                 dst_span: span,
                 src_span: other.span(),
@@ -146,32 +603,74 @@ fn replace(val: &Value, args: &Arguments, span: Span) -> Value {
     }
 }
 
-fn replace_impl(input: &[u8], arg: &Arguments, span: Span) -> Value {
+/// ASCII case-insensitive byte equality; non-ASCII bytes must match exactly.
+fn bytes_eq_ascii_ignore_case(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .all(|(x, y)| x.to_ascii_lowercase() == y.to_ascii_lowercase())
+}
+
+fn bytes_eq(a: &[u8], b: &[u8], ignore_case: bool) -> bool {
+    if ignore_case {
+        bytes_eq_ascii_ignore_case(a, b)
+    } else {
+        a == b
+    }
+}
+
+/// Find the first pattern (in list order) that matches `input` at `pos`, if any.
+fn matching_pattern_at<'a>(
+    input: &[u8],
+    pos: usize,
+    patterns: &'a [Pattern],
+    ignore_case: bool,
+) -> Option<&'a Pattern> {
+    patterns.iter().find(|pattern| {
+        let end = pos + pattern.find.len();
+        end <= input.len() && bytes_eq(&input[pos..end], &pattern.find, ignore_case)
+    })
+}
+
+fn replace_impl(input: &[u8], arg: &Arguments, span: Span, from_string: bool) -> Value {
     let mut replaced = vec![];
     let replace_all = arg.all;
 
-    // doing find-and-replace stuff.
-    let (mut left, mut right) = (0, arg.find.len());
     let input_len = input.len();
-    let pattern_len = arg.find.len();
-    while right <= input_len {
-        if input[left..right] == arg.find {
-            let mut to_replace = arg.replace.clone();
-            replaced.append(&mut to_replace);
-            left += pattern_len;
-            right += pattern_len;
-            if !replace_all {
-                break;
+    let mut pos = 0;
+    while pos < input_len {
+        match matching_pattern_at(input, pos, &arg.patterns, arg.ignore_case) {
+            Some(pattern) => {
+                replaced.extend_from_slice(&pattern.replace);
+                if let Some(max_size) = arg.max_size {
+                    if replaced.len() as i64 > max_size {
+                        return Value::error(max_size_exceeded_error(max_size, span), span);
+                    }
+                }
+                pos += pattern.find.len();
+                if !replace_all {
+                    break;
+                }
             }
-        } else {
-            replaced.push(input[left]);
-            left += 1;
-            right += 1;
+            None => {
+                replaced.push(input[pos]);
+                pos += 1;
+            }
+        }
+    }
+
+    replaced.extend_from_slice(&input[pos..]);
+    if let Some(max_size) = arg.max_size {
+        if replaced.len() as i64 > max_size {
+            return Value::error(max_size_exceeded_error(max_size, span), span);
         }
     }
 
-    let mut remain = input[left..].to_vec();
-    replaced.append(&mut remain);
+    if from_string && !arg.output_binary {
+        if let Ok(s) = String::from_utf8(replaced.clone()) {
+            return Value::string(s, span);
+        }
+    }
     Value::binary(replaced, span)
 }
 
@@ -185,4 +684,234 @@ mod tests {
 
         test_examples(BytesReplace {})
     }
+
+    #[test]
+    fn test_ignore_case_mixed_ascii() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![Pattern {
+                find: b"aB".to_vec(),
+                replace: b"X".to_vec(),
+            }],
+            cell_paths: None,
+            all: true,
+            ignore_case: true,
+            output_binary: false,
+            max_size: None,
+        };
+        let actual = replace_impl(b"AbaBAb", &args, span, false);
+        assert_eq!(actual, Value::binary(b"XXX".to_vec(), span));
+    }
+
+    #[test]
+    fn test_find_longer_than_input_returns_input_unchanged() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![Pattern {
+                find: b"abcde".to_vec(),
+                replace: b"X".to_vec(),
+            }],
+            cell_paths: None,
+            all: true,
+            ignore_case: false,
+            output_binary: false,
+            max_size: None,
+        };
+        let actual = replace_impl(b"abcd", &args, span, false);
+        assert_eq!(actual, Value::binary(b"abcd".to_vec(), span));
+    }
+
+    #[test]
+    fn test_input_shorter_than_find_by_one_byte_returns_input_unchanged() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![Pattern {
+                find: b"abcd".to_vec(),
+                replace: b"X".to_vec(),
+            }],
+            cell_paths: None,
+            all: true,
+            ignore_case: false,
+            output_binary: false,
+            max_size: None,
+        };
+        let actual = replace_impl(b"abc", &args, span, false);
+        assert_eq!(actual, Value::binary(b"abc".to_vec(), span));
+    }
+
+    #[test]
+    fn test_find_equal_to_input_replaces_fully() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![Pattern {
+                find: b"abcd".to_vec(),
+                replace: b"X".to_vec(),
+            }],
+            cell_paths: None,
+            all: true,
+            ignore_case: false,
+            output_binary: false,
+            max_size: None,
+        };
+        let actual = replace_impl(b"abcd", &args, span, false);
+        assert_eq!(actual, Value::binary(b"X".to_vec(), span));
+    }
+
+    #[test]
+    fn test_multiple_patterns_in_one_scan() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![
+                Pattern {
+                    find: b"a".to_vec(),
+                    replace: b"1".to_vec(),
+                },
+                Pattern {
+                    find: b"b".to_vec(),
+                    replace: b"2".to_vec(),
+                },
+                Pattern {
+                    find: b"c".to_vec(),
+                    replace: b"3".to_vec(),
+                },
+            ],
+            cell_paths: None,
+            all: true,
+            ignore_case: false,
+            output_binary: false,
+            max_size: None,
+        };
+        let actual = replace_impl(b"cabbage", &args, span, false);
+        assert_eq!(actual, Value::binary(b"31221ge".to_vec(), span));
+    }
+
+    #[test]
+    fn test_multiple_patterns_first_listed_wins_on_overlap() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![
+                Pattern {
+                    find: b"ab".to_vec(),
+                    replace: b"X".to_vec(),
+                },
+                Pattern {
+                    find: b"a".to_vec(),
+                    replace: b"Y".to_vec(),
+                },
+            ],
+            cell_paths: None,
+            all: true,
+            ignore_case: false,
+            output_binary: false,
+            max_size: None,
+        };
+        let actual = replace_impl(b"ab a", &args, span, false);
+        assert_eq!(actual, Value::binary(b"X Y".to_vec(), span));
+    }
+
+    #[test]
+    fn test_string_input_returns_string_when_result_is_valid_utf8() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![Pattern {
+                find: b"bar".to_vec(),
+                replace: b"baz".to_vec(),
+            }],
+            cell_paths: None,
+            all: false,
+            ignore_case: false,
+            output_binary: false,
+            max_size: None,
+        };
+        let actual = replace_impl(b"foo bar", &args, span, true);
+        assert_eq!(actual, Value::string("foo baz".to_string(), span));
+    }
+
+    #[test]
+    fn test_replace_over_a_list_of_three_binaries() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![Pattern {
+                find: b"a".to_vec(),
+                replace: b"X".to_vec(),
+            }],
+            cell_paths: None,
+            all: true,
+            ignore_case: false,
+            output_binary: false,
+            max_size: None,
+        };
+
+        let list = vec![
+            Value::binary(b"abc".to_vec(), span),
+            Value::binary(b"aab".to_vec(), span),
+            Value::binary(b"bbb".to_vec(), span),
+        ];
+
+        let replaced: Vec<Value> = list.iter().map(|v| replace(v, &args, span)).collect();
+        assert_eq!(replaced[0], Value::binary(b"Xbc".to_vec(), span));
+        assert_eq!(replaced[1], Value::binary(b"XXb".to_vec(), span));
+        assert_eq!(replaced[2], Value::binary(b"bbb".to_vec(), span));
+    }
+
+    #[test]
+    fn test_replace_errors_per_element_on_a_non_binary_element_in_a_list() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![Pattern {
+                find: b"a".to_vec(),
+                replace: b"X".to_vec(),
+            }],
+            cell_paths: None,
+            all: true,
+            ignore_case: false,
+            output_binary: false,
+            max_size: None,
+        };
+
+        let list = vec![
+            Value::binary(b"abc".to_vec(), span),
+            Value::test_int(42),
+        ];
+
+        let replaced: Vec<Value> = list.iter().map(|v| replace(v, &args, span)).collect();
+        assert!(matches!(replaced[0], Value::Binary { .. }));
+        assert!(matches!(replaced[1], Value::Error { .. }));
+    }
+
+    #[test]
+    fn test_max_size_errors_once_the_replaced_output_would_grow_past_the_limit() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![Pattern {
+                find: b"a".to_vec(),
+                replace: b"aaaaaaaaaa".to_vec(),
+            }],
+            cell_paths: None,
+            all: true,
+            ignore_case: false,
+            output_binary: false,
+            max_size: Some(5),
+        };
+        let actual = replace_impl(b"aaa", &args, span, false);
+        assert!(matches!(actual, Value::Error { .. }));
+    }
+
+    #[test]
+    fn test_output_binary_forces_binary_from_string_input() {
+        let span = Span::test_data();
+        let args = Arguments {
+            patterns: vec![Pattern {
+                find: b"bar".to_vec(),
+                replace: b"baz".to_vec(),
+            }],
+            cell_paths: None,
+            all: false,
+            ignore_case: false,
+            output_binary: true,
+            max_size: None,
+        };
+        let actual = replace_impl(b"foo bar", &args, span, true);
+        assert_eq!(actual, Value::binary(b"foo baz".to_vec(), span));
+    }
 }
@@ -1,4 +1,4 @@
-use nu_cmd_base::input_handler::{operate, CellPathOnlyArgs};
+use nu_cmd_base::input_handler::{operate, CmdArgument};
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::{Call, CellPath},
@@ -6,6 +6,19 @@ use nu_protocol::{
     Category, Example, PipelineData, Record, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 
+struct Arguments {
+    cell_paths: Option<Vec<CellPath>>,
+    truthy: bool,
+    invert: bool,
+    numeric_threshold: f64,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
 #[derive(Clone)]
 pub struct SubCommand;
 
@@ -21,11 +34,28 @@ impl Command for SubCommand {
                 (Type::Number, Type::Bool),
                 (Type::String, Type::Bool),
                 (Type::Bool, Type::Bool),
+                (Type::Binary, Type::Bool),
                 (Type::List(Box::new(Type::Any)), Type::Table(vec![])),
                 (Type::Table(vec![]), Type::Table(vec![])),
                 (Type::Record(vec![]), Type::Record(vec![])),
             ])
             .allow_variants_without_examples(true)
+            .switch(
+                "truthy",
+                "broaden conversion to any value: non-null, non-empty, non-zero becomes true (filesize 0, duration 0, empty string/list, and null become false)",
+                Some('t'),
+            )
+            .switch(
+                "invert",
+                "flip the resulting bool after all other coercion, so e.g. `0 | into bool --invert` is true; error values are never inverted",
+                Some('n'),
+            )
+            .named(
+                "numeric-threshold",
+                SyntaxShape::Float,
+                "a float or numeric string becomes true when its absolute value is at least this (default: f64::EPSILON, so nonzero magnitudes below that are still false)",
+                None,
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -38,6 +68,14 @@ impl Command for SubCommand {
         "Convert value to boolean."
     }
 
+    fn extra_usage(&self) -> &str {
+        "By default, only bool, int, float, string, and 1-byte binary values convert, and \
+         anything else is an error. With --truthy, every value converts: null, false, a zero \
+         int, a float below --numeric-threshold, filesize 0, duration 0, and an empty string, \
+         binary or list are false; everything else (including a non-empty string like \
+         \"false\") is true."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["convert", "boolean", "true", "false", "1", "0"]
     }
@@ -109,6 +147,29 @@ impl Command for SubCommand {
                 example: "'true' | into bool",
                 result: Some(Value::bool(true, span)),
             },
+            Example {
+                description: "convert a single-byte binary flag to boolean",
+                example: "0x[01] | into bool",
+                result: Some(Value::bool(true, span)),
+            },
+            Example {
+                description: "--truthy treats an empty list as false instead of erroring",
+                example: "[[]] | into bool --truthy",
+                result: Some(Value::list(vec![Value::bool(false, span)], span)),
+            },
+            Example {
+                description: "--invert flips the result, saving a follow-up `not`",
+                example: "0 | into bool --invert",
+                result: Some(Value::bool(true, span)),
+            },
+            Example {
+                description: "--numeric-threshold raises the bar for a float to count as true",
+                example: "[0.3 0.7] | into bool --numeric-threshold 0.5",
+                result: Some(Value::list(
+                    vec![Value::bool(false, span), Value::bool(true, span)],
+                    span,
+                )),
+            },
         ]
     }
 }
@@ -120,18 +181,101 @@ fn into_bool(
     input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
     let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
-    let args = CellPathOnlyArgs::from(cell_paths);
-    operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    let truthy = call.has_flag("truthy");
+    let invert = call.has_flag("invert");
+    let numeric_threshold: Option<f64> =
+        call.get_flag(engine_state, stack, "numeric-threshold")?;
+    let numeric_threshold = numeric_threshold.unwrap_or(f64::EPSILON);
+    if cell_paths.is_empty() {
+        let args = Arguments {
+            cell_paths: None,
+            truthy,
+            invert,
+            numeric_threshold,
+        };
+        return operate(action, args, input, call.head, engine_state.ctrlc.clone());
+    }
+
+    // Update each cell path ourselves (rather than going through `operate`) so a failure
+    // can be reported with the offending cell path and row number instead of a bare,
+    // row-agnostic error.
+    let head = call.head;
+    let ctrlc = engine_state.ctrlc.clone();
+    let mut row = 0usize;
+    input.map(
+        move |mut v| {
+            let current_row = row;
+            row += 1;
+            update_row_at_cell_paths(
+                &mut v,
+                &cell_paths,
+                current_row,
+                head,
+                truthy,
+                invert,
+                numeric_threshold,
+            );
+            v
+        },
+        ctrlc,
+    )
+}
+
+/// Apply `action` at each of `cell_paths` on row `row` of `v`, replacing `v` with an error
+/// that names the offending cell path and row if any of them fails.
+fn update_row_at_cell_paths(
+    v: &mut Value,
+    cell_paths: &[CellPath],
+    row: usize,
+    head: Span,
+    truthy: bool,
+    invert: bool,
+    numeric_threshold: f64,
+) {
+    for path in cell_paths {
+        let result = v.update_cell_path(
+            &path.members,
+            Box::new(move |old| match old {
+                Value::Error { .. } => old.clone(),
+                _ => action(
+                    old,
+                    &Arguments {
+                        cell_paths: None,
+                        truthy,
+                        invert,
+                        numeric_threshold,
+                    },
+                    head,
+                ),
+            }),
+        );
+        if let Err(error) = result {
+            *v = Value::error(
+                ShellError::GenericError(
+                    format!(
+                        "unable to convert cell path '{}' at row {row}",
+                        path.into_string()
+                    ),
+                    error.to_string(),
+                    Some(head),
+                    None,
+                    vec![error],
+                ),
+                head,
+            );
+            return;
+        }
+    }
 }
 
-fn string_to_boolean(s: &str, span: Span) -> Result<bool, ShellError> {
+fn string_to_boolean(s: &str, span: Span, numeric_threshold: f64) -> Result<bool, ShellError> {
     match s.trim().to_lowercase().as_str() {
         "true" => Ok(true),
         "false" => Ok(false),
         o => {
             let val = o.parse::<f64>();
             match val {
-                Ok(f) => Ok(f.abs() >= f64::EPSILON),
+                Ok(f) => Ok(f.abs() >= numeric_threshold),
                 Err(_) => Err(ShellError::CantConvert {
                     to_type: "boolean".to_string(),
                     from_type: "string".to_string(),
@@ -146,29 +290,95 @@ fn string_to_boolean(s: &str, span: Span) -> Result<bool, ShellError> {
     }
 }
 
-fn action(input: &Value, _args: &CellPathOnlyArgs, span: Span) -> Value {
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    let result = action_inner(input, args, head);
+
+    match result {
+        Value::Bool { val, .. } if args.invert => Value::bool(!val, result.span()),
+        result => result,
+    }
+}
+
+fn action_inner(input: &Value, args: &Arguments, head: Span) -> Value {
+    let span = input.span();
+
+    // Errors always propagate, in both strict and --truthy modes, and are never inverted.
+    if matches!(input, Value::Error { .. }) {
+        return input.clone();
+    }
+
+    if args.truthy {
+        return Value::bool(is_truthy(input, args.numeric_threshold), span);
+    }
+
     match input {
         Value::Bool { .. } => input.clone(),
         Value::Int { val, .. } => Value::bool(*val != 0, span),
-        Value::Float { val, .. } => Value::bool(val.abs() >= f64::EPSILON, span),
-        Value::String { val, .. } => match string_to_boolean(val, span) {
+        Value::Float { val, .. } => {
+            if val.is_nan() {
+                Value::error(
+                    ShellError::CantConvert {
+                        to_type: "boolean".to_string(),
+                        from_type: "float".to_string(),
+                        span,
+                        help: Some("NaN has no well-defined truthiness".to_string()),
+                    },
+                    span,
+                )
+            } else {
+                Value::bool(val.abs() >= args.numeric_threshold, span)
+            }
+        }
+        Value::String { val, .. } => match string_to_boolean(val, span, args.numeric_threshold) {
             Ok(val) => Value::bool(val, span),
             Err(error) => Value::error(error, span),
         },
-        // Propagate errors by explicitly matching them before the final case.
-        Value::Error { .. } => input.clone(),
+        Value::Binary { val, .. } => match val.as_slice() {
+            [byte] => Value::bool(*byte != 0, span),
+            _ => Value::error(
+                ShellError::GenericError(
+                    "unable to convert binary to boolean".to_string(),
+                    format!(
+                        "binary must be exactly 1 byte to convert to bool, got {} bytes",
+                        val.len()
+                    ),
+                    Some(span),
+                    Some("reduce it first, e.g. with `bytes at 0..1`".to_string()),
+                    vec![],
+                ),
+                span,
+            ),
+        },
         other => Value::error(
             ShellError::OnlySupportsThisInputType {
-                exp_input_type: "bool, integer, float or string".into(),
+                exp_input_type: "bool, integer, float, string or 1-byte binary".into(),
                 wrong_type: other.get_type().to_string(),
-                dst_span: span,
+                dst_span: head,
                 src_span: other.span(),
             },
-            span,
+            head,
         ),
     }
 }
 
+/// Broad truthiness used by `--truthy`: null, `false`, a zero filesize/duration/number, and an
+/// empty string/binary/list are false; everything else (including a non-empty string like
+/// "false") is true.
+fn is_truthy(value: &Value, numeric_threshold: f64) -> bool {
+    match value {
+        Value::Nothing { .. } => false,
+        Value::Bool { val, .. } => *val,
+        Value::Int { val, .. } => *val != 0,
+        Value::Float { val, .. } => val.abs() >= numeric_threshold,
+        Value::Filesize { val, .. } => *val != 0,
+        Value::Duration { val, .. } => *val != 0,
+        Value::String { val, .. } => !val.is_empty(),
+        Value::Binary { val, .. } => !val.is_empty(),
+        Value::List { vals, .. } => !vals.is_empty(),
+        _ => true,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -179,4 +389,261 @@ mod test {
 
         test_examples(SubCommand {})
     }
+
+    #[test]
+    fn test_string_to_bool_keeps_input_span_not_call_head() {
+        let input_span = Span::new(5, 9);
+        let call_head = Span::new(100, 110);
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: false,
+            numeric_threshold: f64::EPSILON,
+        };
+
+        let input = Value::string("true", input_span);
+        let actual = action(&input, &args, call_head);
+
+        assert_eq!(actual.span(), input_span);
+        assert_ne!(actual.span(), call_head);
+    }
+
+    #[test]
+    fn test_zero_byte_binary_is_false() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: false,
+            numeric_threshold: f64::EPSILON,
+        };
+        let input = Value::binary(vec![0x00], span);
+        let actual = action(&input, &args, span);
+        assert_eq!(actual, Value::bool(false, span));
+    }
+
+    #[test]
+    fn test_nonzero_byte_binary_is_true() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: false,
+            numeric_threshold: f64::EPSILON,
+        };
+        let input = Value::binary(vec![0x01], span);
+        let actual = action(&input, &args, span);
+        assert_eq!(actual, Value::bool(true, span));
+    }
+
+    #[test]
+    fn test_multibyte_binary_is_error() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: false,
+            numeric_threshold: f64::EPSILON,
+        };
+        let input = Value::binary(vec![0x01, 0x02], span);
+        let actual = action(&input, &args, span);
+        assert!(matches!(actual, Value::Error { .. }));
+    }
+
+    #[test]
+    fn test_nan_is_error() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: false,
+            numeric_threshold: f64::EPSILON,
+        };
+        let input = Value::float(f64::NAN, span);
+        let actual = action(&input, &args, span);
+        assert!(matches!(actual, Value::Error { .. }));
+    }
+
+    #[test]
+    fn test_missing_cell_path_names_path_and_row() {
+        use nu_protocol::ast::PathMember;
+
+        let span = Span::test_data();
+        let cell_path = CellPath {
+            members: vec![PathMember::String {
+                val: "value".to_string(),
+                span,
+                optional: false,
+            }],
+        };
+
+        let mut row_with_column = Value::test_record(Record {
+            cols: vec!["value".to_string()],
+            vals: vec![Value::test_string("true")],
+        });
+        update_row_at_cell_paths(
+            &mut row_with_column,
+            &[cell_path.clone()],
+            0,
+            span,
+            false,
+            false,
+            f64::EPSILON,
+        );
+        assert!(!matches!(row_with_column, Value::Error { .. }));
+
+        let mut row_missing_column = Value::test_record(Record {
+            cols: vec!["other".to_string()],
+            vals: vec![Value::test_string("true")],
+        });
+        update_row_at_cell_paths(
+            &mut row_missing_column,
+            &[cell_path],
+            1,
+            span,
+            false,
+            false,
+            f64::EPSILON,
+        );
+        match row_missing_column {
+            Value::Error { error, .. } => {
+                let msg = error.to_string();
+                assert!(msg.contains("value"));
+                assert!(msg.contains("row 1"));
+            }
+            _ => panic!("expected an error for the missing cell path"),
+        }
+    }
+
+    #[test]
+    fn test_truthy_covers_types_that_are_otherwise_errors() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: true,
+            invert: false,
+            numeric_threshold: f64::EPSILON,
+        };
+
+        assert_eq!(
+            action(&Value::nothing(span), &args, span),
+            Value::bool(false, span)
+        );
+        assert_eq!(
+            action(&Value::filesize(0, span), &args, span),
+            Value::bool(false, span)
+        );
+        assert_eq!(
+            action(&Value::filesize(1, span), &args, span),
+            Value::bool(true, span)
+        );
+        assert_eq!(
+            action(&Value::duration(0, span), &args, span),
+            Value::bool(false, span)
+        );
+        assert_eq!(
+            action(&Value::duration(1, span), &args, span),
+            Value::bool(true, span)
+        );
+        assert_eq!(
+            action(&Value::test_string(""), &args, span),
+            Value::bool(false, span)
+        );
+        assert_eq!(
+            action(&Value::test_string("false"), &args, span),
+            Value::bool(true, span)
+        );
+        assert_eq!(
+            action(&Value::list(vec![], span), &args, span),
+            Value::bool(false, span)
+        );
+        assert_eq!(
+            action(&Value::list(vec![Value::test_int(0)], span), &args, span),
+            Value::bool(true, span)
+        );
+        assert_eq!(
+            action(&Value::binary(vec![1, 2, 3], span), &args, span),
+            Value::bool(true, span)
+        );
+    }
+
+    #[test]
+    fn test_invert_flips_an_int_conversion() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: true,
+            numeric_threshold: f64::EPSILON,
+        };
+        let actual = action(&Value::int(0, span), &args, span);
+        assert_eq!(actual, Value::bool(true, span));
+
+        let actual = action(&Value::int(1, span), &args, span);
+        assert_eq!(actual, Value::bool(false, span));
+    }
+
+    #[test]
+    fn test_invert_flips_a_string_conversion() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: true,
+            numeric_threshold: f64::EPSILON,
+        };
+        let actual = action(&Value::test_string("false"), &args, span);
+        assert_eq!(actual, Value::bool(true, span));
+
+        let actual = action(&Value::test_string("true"), &args, span);
+        assert_eq!(actual, Value::bool(false, span));
+    }
+
+    #[test]
+    fn test_numeric_threshold_over_a_list_of_floats() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: false,
+            numeric_threshold: 0.5,
+        };
+
+        let actual = action(&Value::test_float(0.3), &args, span);
+        assert_eq!(actual, Value::bool(false, span));
+
+        let actual = action(&Value::test_float(0.7), &args, span);
+        assert_eq!(actual, Value::bool(true, span));
+    }
+
+    #[test]
+    fn test_numeric_threshold_also_applies_to_numeric_strings() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: false,
+            numeric_threshold: 0.5,
+        };
+
+        let actual = action(&Value::test_string("0.3"), &args, span);
+        assert_eq!(actual, Value::bool(false, span));
+
+        let actual = action(&Value::test_string("0.7"), &args, span);
+        assert_eq!(actual, Value::bool(true, span));
+    }
+
+    #[test]
+    fn test_invert_does_not_affect_error_values() {
+        let span = Span::test_data();
+        let args = Arguments {
+            cell_paths: None,
+            truthy: false,
+            invert: true,
+            numeric_threshold: f64::EPSILON,
+        };
+        let input = Value::binary(vec![0x01, 0x02], span);
+        let actual = action(&input, &args, span);
+        assert!(matches!(actual, Value::Error { .. }));
+    }
 }
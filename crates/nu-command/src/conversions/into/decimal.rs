@@ -1,10 +1,41 @@
-use nu_cmd_base::input_handler::{operate, CellPathOnlyArgs};
+use nu_cmd_base::input_handler::{operate, operate_parallel, CmdArgument};
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::{Call, CellPath},
     engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, Record, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    Category, Example, PipelineData, Record, ShellError, Signature, Span, Spanned, SyntaxShape,
+    Type, Value,
 };
+use chrono::{DateTime, FixedOffset};
+
+const NS_PER_SEC: i64 = 1_000_000_000;
+const DURATION_UNITS: &[(&str, i64)] = &[
+    ("ns", 1),
+    ("us", 1_000),
+    ("ms", 1_000_000),
+    ("sec", NS_PER_SEC),
+    ("min", 60 * NS_PER_SEC),
+    ("hr", 60 * 60 * NS_PER_SEC),
+    ("day", 24 * 60 * 60 * NS_PER_SEC),
+    ("wk", 7 * 24 * 60 * 60 * NS_PER_SEC),
+];
+
+struct Arguments {
+    cell_paths: Option<Vec<CellPath>>,
+    fraction: bool,
+    duration_unit: String,
+    currency: bool,
+    group_separator: Option<String>,
+    decimal_separator: Option<String>,
+    comma_decimal: bool,
+    from_date: bool,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -21,6 +52,9 @@ impl Command for SubCommand {
                 (Type::String, Type::Float),
                 (Type::Bool, Type::Float),
                 (Type::Float, Type::Float),
+                (Type::Filesize, Type::Float),
+                (Type::Duration, Type::Float),
+                (Type::Date, Type::Float),
                 (Type::Table(vec![]), Type::Table(vec![])),
                 (Type::Record(vec![]), Type::Record(vec![])),
                 (
@@ -28,6 +62,54 @@ impl Command for SubCommand {
                     Type::List(Box::new(Type::Float)),
                 ),
             ])
+            .switch(
+                "fraction",
+                "Parse the input as a \"numerator/denominator\" fraction string",
+                None,
+            )
+            .switch(
+                "fail-fast",
+                "Error the whole pipeline on the first element that fails to convert, instead of returning an error value for that element",
+                None,
+            )
+            .switch(
+                "parallel",
+                "convert elements across threads instead of sequentially, for large tables where conversion touches every cell; output order is unchanged",
+                None,
+            )
+            .switch(
+                "currency",
+                "strip a leading or trailing currency symbol (e.g. $, \u{20ac}) before parsing; implies --group-separator/--decimal-separator defaults apply",
+                None,
+            )
+            .switch(
+                "from-date",
+                "interpret the input as a date, converting it to seconds since the Unix epoch (1970-01-01T00:00:00Z) as a float with sub-second precision",
+                None,
+            )
+            .named(
+                "group-separator",
+                SyntaxShape::String,
+                "character used to group digits, e.g. ',' in \"1,234.56\" (default: ',')",
+                None,
+            )
+            .named(
+                "decimal-separator",
+                SyntaxShape::String,
+                "character used as the decimal point, e.g. ',' in \"1.234,56\" (default: '.')",
+                None,
+            )
+            .switch(
+                "comma-decimal",
+                "shorthand for --decimal-separator ',' --group-separator '' (no group separator), for locales where ',' is the decimal point and numbers like \"3,14\" appear unambiguously; rejects strings with more than one comma as ambiguous. Incompatible with --group-separator/--decimal-separator",
+                None,
+            )
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "for a duration input, the unit to express the result in: ns, us, ms, sec, min, hr, day, or wk (default: sec)",
+                None,
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -41,6 +123,10 @@ impl Command for SubCommand {
         "Convert text into a decimal."
     }
 
+    fn extra_usage(&self) -> &str {
+        "Filesize input becomes its byte count; duration input becomes its length in `--unit` (default: seconds). With --from-date, date input becomes its Unix timestamp in seconds, as a float so sub-second precision survives (unlike `into int`'s nanosecond integer timestamp). By default, an element that fails to convert becomes an error value in the output rather than stopping the pipeline; pass --fail-fast to make the first such failure a hard error instead. --parallel converts across threads instead of sequentially, for large tables where conversion touches every cell; it collects the whole input up front, so it doesn't stream and can't be combined with --fail-fast."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["convert", "number", "floating"]
     }
@@ -53,7 +139,102 @@ impl Command for SubCommand {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
-        let args = CellPathOnlyArgs::from(cell_paths);
+        let unit: Option<Spanned<String>> = call.get_flag(engine_state, stack, "unit")?;
+        let duration_unit = match unit {
+            Some(unit) => {
+                if !DURATION_UNITS.iter().any(|(name, _)| *name == unit.item) {
+                    let units: Vec<&str> = DURATION_UNITS.iter().map(|(name, _)| *name).collect();
+                    return Err(ShellError::IncorrectValue {
+                        msg: format!("unit must be one of: {}", units.join(", ")),
+                        val_span: unit.span,
+                        call_span: call.head,
+                    });
+                }
+                unit.item
+            }
+            None => "sec".to_string(),
+        };
+
+        let group_separator: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "group-separator")?;
+        let decimal_separator: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "decimal-separator")?;
+        let currency = call.has_flag("currency");
+
+        if let (Some(group_separator), Some(decimal_separator)) =
+            (&group_separator, &decimal_separator)
+        {
+            if group_separator.item == decimal_separator.item {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: "--group-separator".to_string(),
+                    left_span: group_separator.span,
+                    right_message: "--decimal-separator".to_string(),
+                    right_span: decimal_separator.span,
+                });
+            }
+        }
+
+        let comma_decimal = call.get_named_arg("comma-decimal");
+        if let Some(comma_decimal) = &comma_decimal {
+            if let Some(group_separator) = &group_separator {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: "--comma-decimal".to_string(),
+                    left_span: comma_decimal.span,
+                    right_message: "--group-separator".to_string(),
+                    right_span: group_separator.span,
+                });
+            }
+            if let Some(decimal_separator) = &decimal_separator {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: "--comma-decimal".to_string(),
+                    left_span: comma_decimal.span,
+                    right_message: "--decimal-separator".to_string(),
+                    right_span: decimal_separator.span,
+                });
+            }
+        }
+        let comma_decimal = comma_decimal.is_some();
+
+        let (group_separator, decimal_separator) = if comma_decimal {
+            (Some(String::new()), Some(",".to_string()))
+        } else {
+            (
+                group_separator.map(|s| s.item),
+                decimal_separator.map(|s| s.item),
+            )
+        };
+
+        if let (Some(fail_fast), Some(parallel)) = (
+            call.get_named_arg("fail-fast"),
+            call.get_named_arg("parallel"),
+        ) {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--fail-fast".to_string(),
+                left_span: fail_fast.span,
+                right_message: "--parallel".to_string(),
+                right_span: parallel.span,
+            });
+        }
+
+        let args = Arguments {
+            cell_paths: (!cell_paths.is_empty()).then_some(cell_paths),
+            fraction: call.has_flag("fraction"),
+            duration_unit,
+            currency,
+            group_separator,
+            decimal_separator,
+            comma_decimal,
+            from_date: call.has_flag("from-date"),
+        };
+
+        if call.has_flag("fail-fast") {
+            return fail_fast_over_input(input, args, call.head);
+        }
+
+        if call.has_flag("parallel") {
+            return operate_parallel(action, args, input, call.head);
+        }
+
         operate(action, args, input, call.head, engine_state.ctrlc.clone())
     }
 
@@ -88,14 +269,242 @@ impl Command for SubCommand {
                 example: "true | into decimal",
                 result: Some(Value::test_float(1.0)),
             },
+            Example {
+                description: "Convert a fraction string to decimal",
+                example: "'3/4' | into decimal --fraction",
+                result: Some(Value::test_float(0.75)),
+            },
+            Example {
+                description: "Convert a filesize to its byte count",
+                example: "1KiB | into decimal",
+                result: Some(Value::test_float(1024.0)),
+            },
+            Example {
+                description: "Convert a duration to seconds",
+                example: "1min | into decimal",
+                result: Some(Value::test_float(60.0)),
+            },
+            Example {
+                description: "Convert a duration to milliseconds",
+                example: "1sec | into decimal --unit ms",
+                result: Some(Value::test_float(1000.0)),
+            },
+            Example {
+                description: "Stop the whole pipeline on the first value that fails to convert",
+                example: "['1.5' 'oops'] | into decimal --fail-fast",
+                result: None,
+            },
+            Example {
+                description: "Convert a dollar-formatted currency string",
+                example: "'$1,234.56' | into decimal --currency",
+                result: Some(Value::test_float(1234.56)),
+            },
+            Example {
+                description: "Convert a euro-formatted currency string using European separators",
+                example: "'€1.234,56' | into decimal --currency --group-separator '.' --decimal-separator ','",
+                result: Some(Value::test_float(1234.56)),
+            },
+            Example {
+                description: "Convert a comma-decimal string from a locale where ',' is the decimal point",
+                example: "'3,14' | into decimal --comma-decimal",
+                result: Some(Value::test_float(3.14)),
+            },
+            Example {
+                description: "Convert a date to a Unix timestamp in seconds, with sub-second precision",
+                example: "1970-01-01T00:00:01.5Z | into decimal --from-date",
+                result: Some(Value::test_float(1.5)),
+            },
+            Example {
+                description: "Convert a large table's column across threads instead of sequentially",
+                example: "[[num]; ['5.01'] ['6.02']] | into decimal num --parallel",
+                result: Some(Value::list(
+                    vec![
+                        Value::test_record(Record {
+                            cols: vec!["num".to_string()],
+                            vals: vec![Value::test_float(5.01)],
+                        }),
+                        Value::test_record(Record {
+                            cols: vec!["num".to_string()],
+                            vals: vec![Value::test_float(6.02)],
+                        }),
+                    ],
+                    Span::test_data(),
+                )),
+            },
         ]
     }
 }
 
-fn action(input: &Value, _args: &CellPathOnlyArgs, head: Span) -> Value {
+/// Parses `"numerator/denominator"`, trimming whitespace around the slash.
+fn parse_fraction(s: &str, head: Span, span: Span) -> Value {
+    let Some((numerator, denominator)) = s.split_once('/') else {
+        return Value::error(
+            ShellError::CantConvert {
+                to_type: "float".to_string(),
+                from_type: "fraction".to_string(),
+                span,
+                help: Some("expected a \"numerator/denominator\" string, e.g. \"3/4\"".to_string()),
+            },
+            span,
+        );
+    };
+
+    let parse_part = |part: &str| part.trim().parse::<f64>();
+    match (parse_part(numerator), parse_part(denominator)) {
+        (Ok(numerator), Ok(denominator)) => {
+            if denominator == 0.0 {
+                Value::error(ShellError::DivisionByZero { span }, span)
+            } else {
+                Value::float(numerator / denominator, head)
+            }
+        }
+        _ => Value::error(
+            ShellError::CantConvert {
+                to_type: "float".to_string(),
+                from_type: "fraction".to_string(),
+                span,
+                help: Some("expected a \"numerator/denominator\" string, e.g. \"3/4\"".to_string()),
+            },
+            span,
+        ),
+    }
+}
+
+/// Parse a currency- or locale-formatted decimal string like `"$1,234.56"` or `"€1.234,56"`
+/// under `--currency`/`--group-separator`/`--decimal-separator`: strip a leading or trailing
+/// currency symbol (when `--currency` is given), drop the group separator, normalize the
+/// decimal separator to `.`, then parse. `--group-separator`/`--decimal-separator` already
+/// reject being set to the same character before this is reached, so any remaining failure
+/// to parse means the string itself is ambiguous or malformed.
+fn parse_formatted_decimal(s: &str, args: &Arguments, head: Span, span: Span) -> Value {
+    let group_separator = args.group_separator.as_deref().unwrap_or(",");
+    let decimal_separator = args.decimal_separator.as_deref().unwrap_or(".");
+
+    let trimmed = s.trim();
+    let trimmed = if args.currency {
+        strip_currency_symbol(trimmed, group_separator, decimal_separator)
+    } else {
+        trimmed
+    };
+
+    if args.comma_decimal {
+        let comma_count = trimmed.matches(',').count();
+        if comma_count > 1 {
+            return Value::error(
+                ShellError::CantConvert {
+                    to_type: "float".to_string(),
+                    from_type: "comma-decimal string".to_string(),
+                    span,
+                    help: Some(format!(
+                        "'{s}' has {comma_count} commas, which is ambiguous as a single decimal separator under --comma-decimal"
+                    )),
+                },
+                span,
+            );
+        }
+    }
+
+    let normalized = trimmed
+        .replace(group_separator, "")
+        .replace(decimal_separator, ".");
+
+    match normalized.parse::<f64>() {
+        Ok(x) => Value::float(x, head),
+        Err(_) => Value::error(
+            ShellError::CantConvert {
+                to_type: "float".to_string(),
+                from_type: "currency-formatted string".to_string(),
+                span,
+                help: Some(format!(
+                    "'{s}' is ambiguous or malformed after stripping a currency symbol and applying group separator '{group_separator}' and decimal separator '{decimal_separator}'"
+                )),
+            },
+            span,
+        ),
+    }
+}
+
+/// Strip a leading or trailing currency symbol (e.g. `$`, `€`, `USD`) from `s`, leaving digits,
+/// a leading `-` sign, and the configured group/decimal separator characters untouched.
+fn strip_currency_symbol<'a>(s: &'a str, group_separator: &str, decimal_separator: &str) -> &'a str {
+    let is_amount_char = |c: char| {
+        c.is_ascii_digit() || c == '-' || group_separator.contains(c) || decimal_separator.contains(c)
+    };
+    s.trim_matches(|c: char| !is_amount_char(c) && !c.is_whitespace())
+        .trim()
+}
+
+/// Drive the `--fail-fast` path: apply [`action`] eagerly, element by element, stopping at
+/// the first failure with a hard [`ShellError`] instead of letting [`operate`] stream the
+/// failure through as a `Value::Error` entry.
+fn fail_fast_over_input(
+    input: PipelineData,
+    args: Arguments,
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    match input {
+        PipelineData::Value(Value::List { vals, .. }, metadata) => {
+            let vals = vals
+                .into_iter()
+                .map(|val| action_or_fail_fast(val, &args, head))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(PipelineData::Value(Value::list(vals, head), metadata))
+        }
+        PipelineData::ListStream(stream, metadata) => {
+            let vals = stream
+                .into_iter()
+                .map(|val| action_or_fail_fast(val, &args, head))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(PipelineData::Value(Value::list(vals, head), metadata))
+        }
+        PipelineData::Value(value, metadata) => {
+            let value = action_or_fail_fast(value, &args, head)?;
+            Ok(PipelineData::Value(value, metadata))
+        }
+        other => operate(action, args, other, head, None),
+    }
+}
+
+/// Apply [`action`] to `value`, respecting `args.cell_paths` the same way [`operate`] does,
+/// but return `Err` the moment a conversion fails instead of embedding a `Value::Error` in
+/// the result. `update_cell_path` already turns a `Value::Error` returned from its callback
+/// into an `Err`, so there's no need to special-case that here.
+fn action_or_fail_fast(value: Value, args: &Arguments, head: Span) -> Result<Value, ShellError> {
+    match &args.cell_paths {
+        None => match action(&value, args, head) {
+            Value::Error { error, .. } => Err(*error),
+            other => Ok(other),
+        },
+        Some(cell_paths) => {
+            let mut value = value;
+            for path in cell_paths {
+                value.update_cell_path(
+                    &path.members,
+                    Box::new(|old| action(old, args, head)),
+                )?;
+            }
+            Ok(value)
+        }
+    }
+}
+
+/// Convert a date to seconds since the Unix epoch, as a float so sub-second precision
+/// survives (unlike `into int`'s nanosecond integer timestamp, this has no documented
+/// range limit since it isn't bound by `i64` nanoseconds).
+fn date_to_unix_seconds(val: &DateTime<FixedOffset>, head: Span) -> Value {
+    let seconds = val.timestamp() as f64 + val.timestamp_subsec_nanos() as f64 / 1_000_000_000.0;
+    Value::float(seconds, head)
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
     let span = input.span();
     match input {
         Value::Float { .. } => input.clone(),
+        Value::Date { val, .. } if args.from_date => date_to_unix_seconds(val, head),
+        Value::String { val: s, .. } if args.fraction => parse_fraction(s, head, span),
+        Value::String { val: s, .. } if args.currency || args.group_separator.is_some() || args.decimal_separator.is_some() => {
+            parse_formatted_decimal(s, args, head, span)
+        }
         Value::String { val: s, .. } => {
             let other = s.trim();
 
@@ -113,6 +522,15 @@ fn action(input: &Value, _args: &CellPathOnlyArgs, head: Span) -> Value {
             }
         }
         Value::Int { val: v, .. } => Value::float(*v as f64, span),
+        Value::Filesize { val, .. } => Value::float(*val as f64, span),
+        Value::Duration { val, .. } => {
+            let divisor = DURATION_UNITS
+                .iter()
+                .find(|(name, _)| *name == args.duration_unit)
+                .map(|(_, divisor)| *divisor)
+                .unwrap_or(NS_PER_SEC);
+            Value::float(*val as f64 / divisor as f64, span)
+        }
         Value::Bool { val: b, .. } => Value::float(
             match b {
                 true => 1.0,
@@ -124,7 +542,7 @@ fn action(input: &Value, _args: &CellPathOnlyArgs, head: Span) -> Value {
         Value::Error { .. } => input.clone(),
         other => Value::error(
             ShellError::OnlySupportsThisInputType {
-                exp_input_type: "string, integer or bool".into(),
+                exp_input_type: "string, integer, bool, filesize, duration, or date (with --from-date)".into(),
                 wrong_type: other.get_type().to_string(),
                 dst_span: head,
                 src_span: other.span(),
@@ -139,6 +557,19 @@ mod tests {
     use super::*;
     use nu_protocol::Type::Error;
 
+    fn args(fraction: bool) -> Arguments {
+        Arguments {
+            cell_paths: None,
+            fraction,
+            duration_unit: "sec".to_string(),
+            currency: false,
+            group_separator: None,
+            decimal_separator: None,
+            comma_decimal: false,
+            from_date: false,
+        }
+    }
+
     #[test]
     fn test_examples() {
         use crate::test_examples;
@@ -152,7 +583,7 @@ mod tests {
         let word = Value::test_string("3.1415");
         let expected = Value::test_float(3.1415);
 
-        let actual = action(&word, &CellPathOnlyArgs::from(vec![]), Span::test_data());
+        let actual = action(&word, &args(false), Span::test_data());
         assert_eq!(actual, expected);
     }
 
@@ -160,11 +591,7 @@ mod tests {
     fn communicates_parsing_error_given_an_invalid_decimallike_string() {
         let decimal_str = Value::test_string("11.6anra");
 
-        let actual = action(
-            &decimal_str,
-            &CellPathOnlyArgs::from(vec![]),
-            Span::test_data(),
-        );
+        let actual = action(&decimal_str, &args(false), Span::test_data());
 
         assert_eq!(actual.get_type(), Error);
     }
@@ -173,12 +600,255 @@ mod tests {
     fn int_to_decimal() {
         let decimal_str = Value::test_int(10);
         let expected = Value::test_float(10.0);
-        let actual = action(
-            &decimal_str,
-            &CellPathOnlyArgs::from(vec![]),
-            Span::test_data(),
-        );
+        let actual = action(&decimal_str, &args(false), Span::test_data());
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn proper_fraction_to_decimal() {
+        let fraction = Value::test_string("3/4");
+        let expected = Value::test_float(0.75);
+
+        let actual = action(&fraction, &args(true), Span::test_data());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn improper_fraction_to_decimal() {
+        let fraction = Value::test_string(" 9 / 2 ");
+        let expected = Value::test_float(4.5);
+
+        let actual = action(&fraction, &args(true), Span::test_data());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn negative_fraction_to_decimal() {
+        let fraction = Value::test_string("-3/4");
+        let expected = Value::test_float(-0.75);
+
+        let actual = action(&fraction, &args(true), Span::test_data());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn zero_denominator_fraction_is_an_error() {
+        let fraction = Value::test_string("1/0");
+
+        let actual = action(&fraction, &args(true), Span::test_data());
+        assert_eq!(actual.get_type(), Error);
+    }
+
+    #[test]
+    fn filesize_to_decimal() {
+        let filesize = Value::test_filesize(1024);
+        let expected = Value::test_float(1024.0);
+
+        let actual = action(&filesize, &args(false), Span::test_data());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn duration_to_decimal_defaults_to_seconds() {
+        let duration = Value::test_duration(60 * 1_000_000_000);
+        let expected = Value::test_float(60.0);
+
+        let actual = action(&duration, &args(false), Span::test_data());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn duration_to_decimal_in_chosen_unit() {
+        let duration = Value::test_duration(1_000_000_000);
+        let expected = Value::test_float(1000.0);
 
+        let mut args = args(false);
+        args.duration_unit = "ms".to_string();
+
+        let actual = action(&duration, &args, Span::test_data());
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn default_behavior_turns_a_failed_element_into_an_error_value_not_a_hard_error() {
+        let values = vec![Value::test_string("1.5"), Value::test_string("oops")];
+        let input = PipelineData::Value(Value::test_list(values), None);
+
+        let result = operate(action, args(false), input, Span::test_data(), None)
+            .expect("per-element errors don't fail the whole pipeline");
+
+        match result.into_value(Span::test_data()) {
+            Value::List { vals, .. } => {
+                assert!(matches!(vals[0], Value::Float { .. }));
+                assert_eq!(vals[1].get_type(), Error);
+            }
+            other => panic!("expected a list, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn currency_parses_a_dollar_formatted_string() {
+        let mut args = args(false);
+        args.currency = true;
+        let actual = action(&Value::test_string("$1,234.56"), &args, Span::test_data());
+        assert_eq!(actual, Value::test_float(1234.56));
+    }
+
+    #[test]
+    fn currency_parses_a_euro_formatted_string_with_custom_separators() {
+        let mut args = args(false);
+        args.currency = true;
+        args.group_separator = Some(".".to_string());
+        args.decimal_separator = Some(",".to_string());
+        let actual = action(&Value::test_string("€1.234,56"), &args, Span::test_data());
+        assert_eq!(actual, Value::test_float(1234.56));
+    }
+
+    #[test]
+    fn currency_handles_a_trailing_symbol_and_a_negative_amount() {
+        let mut args = args(false);
+        args.currency = true;
+        let actual = action(&Value::test_string("-1,234.56 USD"), &args, Span::test_data());
+        assert_eq!(actual, Value::test_float(-1234.56));
+    }
+
+    #[test]
+    fn group_separator_and_decimal_separator_must_differ() {
+        let outcome = nu_test_support::nu!("'1,234.56' | into decimal --group-separator ',' --decimal-separator ','");
+        assert!(outcome.err.contains("incompatible"));
+    }
+
+    #[test]
+    fn comma_decimal_parses_a_comma_decimal_string() {
+        let mut args = args(false);
+        args.comma_decimal = true;
+        args.group_separator = Some(String::new());
+        args.decimal_separator = Some(",".to_string());
+        let actual = action(&Value::test_string("3,14"), &args, Span::test_data());
+        assert_eq!(actual, Value::test_float(3.14));
+    }
+
+    #[test]
+    fn comma_decimal_rejects_more_than_one_comma_as_ambiguous() {
+        let mut args = args(false);
+        args.comma_decimal = true;
+        args.group_separator = Some(String::new());
+        args.decimal_separator = Some(",".to_string());
+        let actual = action(&Value::test_string("3,14,15"), &args, Span::test_data());
+        assert_eq!(actual.get_type(), Error);
+    }
+
+    #[test]
+    fn comma_decimal_is_incompatible_with_group_separator() {
+        let outcome = nu_test_support::nu!("'3,14' | into decimal --comma-decimal --group-separator '.'");
+        assert!(outcome.err.contains("incompatible"));
+    }
+
+    #[test]
+    fn ambiguous_currency_input_is_a_clear_error() {
+        let mut args = args(false);
+        args.currency = true;
+        let actual = action(&Value::test_string("$1.234.56"), &args, Span::test_data());
+        assert_eq!(actual.get_type(), Error);
+    }
+
+    #[test]
+    fn from_date_converts_a_fixed_date_to_a_known_unix_timestamp() {
+        let date: DateTime<FixedOffset> = "2024-01-01T00:00:01.5+00:00".parse().unwrap();
+        let mut args = args(false);
+        args.from_date = true;
+
+        let actual = action(&Value::test_date(date), &args, Span::test_data());
+        assert_eq!(actual, Value::test_float(1704067201.5));
+    }
+
+    #[test]
+    fn date_without_from_date_is_an_error() {
+        let date: DateTime<FixedOffset> = "2024-01-01T00:00:01.5+00:00".parse().unwrap();
+
+        let actual = action(&Value::test_date(date), &args(false), Span::test_data());
+        assert_eq!(actual.get_type(), Error);
+    }
+
+    #[test]
+    fn parallel_produces_the_same_output_as_sequential() {
+        let values: Vec<Value> = (0..200)
+            .map(|i| Value::test_string(format!("{i}.5")))
+            .collect();
+
+        let sequential = operate(
+            action,
+            args(false),
+            PipelineData::Value(Value::test_list(values.clone()), None),
+            Span::test_data(),
+            None,
+        )
+        .expect("sequential path succeeds")
+        .into_value(Span::test_data());
+
+        let parallel = operate_parallel(
+            action,
+            args(false),
+            PipelineData::Value(Value::test_list(values), None),
+            Span::test_data(),
+        )
+        .expect("parallel path succeeds")
+        .into_value(Span::test_data());
+
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn parallel_accepts_a_bare_scalar_input() {
+        let actual = operate_parallel(
+            action,
+            args(false),
+            PipelineData::Value(Value::test_string("5.01"), None),
+            Span::test_data(),
+        )
+        .expect("parallel path succeeds on a scalar")
+        .into_value(Span::test_data());
+
+        assert_eq!(actual, Value::test_float(5.01));
+    }
+
+    #[test]
+    fn parallel_treats_a_bare_binary_value_as_one_scalar_not_one_element_per_byte() {
+        let binary = Value::test_binary(vec![1, 2, 3]);
+
+        let sequential = operate(
+            action,
+            args(false),
+            PipelineData::Value(binary.clone(), None),
+            Span::test_data(),
+            None,
+        )
+        .expect("sequential path succeeds")
+        .into_value(Span::test_data());
+
+        let parallel = operate_parallel(
+            action,
+            args(false),
+            PipelineData::Value(binary, None),
+            Span::test_data(),
+        )
+        .expect("parallel path succeeds")
+        .into_value(Span::test_data());
+
+        // `action` doesn't support binary input at all, so both paths should produce a
+        // single error value for the one (bare) binary scalar, not a list of per-byte results.
+        assert_eq!(sequential.get_type(), Error);
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn fail_fast_stops_the_whole_pipeline_on_the_first_failed_element() {
+        let values = vec![Value::test_string("1.5"), Value::test_string("oops")];
+        let input = PipelineData::Value(Value::test_list(values), None);
+
+        let result = fail_fast_over_input(input, args(false), Span::test_data());
+
+        assert!(result.is_err());
+    }
 }
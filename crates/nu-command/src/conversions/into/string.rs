@@ -1,10 +1,11 @@
+use base64::{alphabet, engine::general_purpose::PAD, engine::GeneralPurpose, Engine};
 use nu_cmd_base::input_handler::{operate, CmdArgument};
 use nu_engine::CallExt;
 use nu_protocol::{
-    ast::{Call, CellPath},
+    ast::{Call, CellPath, RangeInclusion},
     engine::{Command, EngineState, Stack},
-    into_code, Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature,
-    Span, SyntaxShape, Type, Value,
+    into_code, Category, Config, Example, IntoPipelineData, PipelineData, Range, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 use nu_utils::get_system_locale;
 use num_format::ToFormattedString;
@@ -12,6 +13,19 @@ use num_format::ToFormattedString;
 struct Arguments {
     decimals_value: Option<i64>,
     decimals: bool,
+    compact: bool,
+    round_mode: RoundMode,
+    group_digits: bool,
+    trim: bool,
+    pad_left: Option<usize>,
+    pad_right: Option<usize>,
+    pad_char: char,
+    encode: Option<Spanned<String>>,
+    escape: bool,
+    uppercase: bool,
+    when_empty: Option<String>,
+    structured: bool,
+    list_style: ListStyle,
     cell_paths: Option<Vec<CellPath>>,
     config: Config,
 }
@@ -22,6 +36,72 @@ impl CmdArgument for Arguments {
     }
 }
 
+/// How to round a float's fractional digits down to `--decimals` before formatting.
+///
+/// `Default` reproduces the pre-existing behavior: format with Rust's `{:.n$}`, which rounds
+/// half-to-even in some cases and half-away-from-zero in others depending on the value's binary
+/// floating-point representation. The other modes round explicitly, so the outcome no longer
+/// depends on a value's exact binary representation.
+#[derive(Clone, Copy, Default)]
+enum RoundMode {
+    #[default]
+    Default,
+    HalfUp,
+    HalfEven,
+    Trunc,
+    Ceil,
+    Floor,
+}
+
+impl RoundMode {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "half-up" => Some(Self::HalfUp),
+            "half-even" => Some(Self::HalfEven),
+            "trunc" => Some(Self::Trunc),
+            "ceil" => Some(Self::Ceil),
+            "floor" => Some(Self::Floor),
+            _ => None,
+        }
+    }
+}
+
+/// How to join a list's stringified elements into a single string, for `--structured`
+/// (rather than the default of mapping each element to its own string).
+#[derive(Clone, Copy, Default)]
+enum ListStyle {
+    /// `[a, b]`, echoing nuon's list syntax (minus the quoting nuon itself would add).
+    #[default]
+    Nuon,
+    /// `a b`
+    Space,
+    /// `a\nb`
+    Newline,
+    /// `a, b`
+    Comma,
+}
+
+impl ListStyle {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "nuon" => Some(Self::Nuon),
+            "space" => Some(Self::Space),
+            "newline" => Some(Self::Newline),
+            "comma" => Some(Self::Comma),
+            _ => None,
+        }
+    }
+
+    fn join(self, elements: &[String]) -> String {
+        match self {
+            Self::Nuon => format!("[{}]", elements.join(", ")),
+            Self::Space => elements.join(" "),
+            Self::Newline => elements.join("\n"),
+            Self::Comma => elements.join(", "),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct SubCommand;
 
@@ -41,6 +121,7 @@ impl Command for SubCommand {
                 (Type::Filesize, Type::String),
                 (Type::Date, Type::String),
                 (Type::Duration, Type::String),
+                (Type::Range, Type::List(Box::new(Type::String))),
                 (
                     Type::List(Box::new(Type::Any)),
                     Type::List(Box::new(Type::String)),
@@ -60,11 +141,84 @@ impl Command for SubCommand {
                 "decimal digits to which to round",
                 Some('d'),
             )
+            .named(
+                "round-mode",
+                SyntaxShape::String,
+                "how to round when using --decimals: half-up, half-even, trunc, ceil, or floor (default: Rust's standard formatting, which mixes half-to-even and half-away-from-zero depending on the value's binary representation)",
+                None,
+            )
+            .switch(
+                "trim",
+                "trim leading and trailing whitespace from the resulting string",
+                None,
+            )
+            .switch(
+                "compact",
+                "for floats, strip trailing zeros (and a trailing decimal point) after formatting; combines with --decimals as an upper bound on precision",
+                None,
+            )
+            .switch(
+                "group-digits",
+                "group the integer part's digits using the system locale's thousands separator (applies to integers and floats alike)",
+                None,
+            )
+            .named(
+                "pad-left",
+                SyntaxShape::Int,
+                "pad the resulting string on the left to this width (no truncation if it's already wider)",
+                None,
+            )
+            .named(
+                "pad-right",
+                SyntaxShape::Int,
+                "pad the resulting string on the right to this width (no truncation if it's already wider)",
+                None,
+            )
+            .named(
+                "pad-char",
+                SyntaxShape::String,
+                "character to pad with (default: space)",
+                None,
+            )
+            .named(
+                "encode",
+                SyntaxShape::String,
+                "for binary input, stringify it with this encoding instead of erroring: hex, base64, or base64url",
+                None,
+            )
+            .switch(
+                "escape",
+                "for binary input, render it as an escaped string instead of erroring: printable ASCII passes through as-is, `\\` becomes `\\\\`, and every other byte becomes `\\xHH`. Lossless and reversible -- unlike `--encode`, which produces a different (non-binary-shaped) representation, this stays close to the original bytes and can be unescaped back to them exactly",
+                None,
+            )
+            .named(
+                "case",
+                SyntaxShape::String,
+                "letter case to use for hex (`--encode hex`, `--escape`) and boolean output: lower (default) or upper",
+                None,
+            )
+            .named(
+                "when-empty",
+                SyntaxShape::String,
+                "placeholder to use instead of an empty result, covering both null input and an already-empty string (default: keep the empty string)",
+                None,
+            )
+            .switch(
+                "structured",
+                "for a list, stringify the whole list as a single string instead of mapping each element into its own string",
+                None,
+            )
+            .named(
+                "list-style",
+                SyntaxShape::String,
+                "with a list input, how to join the stringified elements: nuon (default, e.g. `[a, b]`), space, newline, or comma; implies --structured",
+                None,
+            )
             .category(Category::Conversions)
     }
 
     fn usage(&self) -> &str {
-        "Convert value to string."
+        "Convert value to string. Binary input is rejected unless `--encode` or `--escape` is given."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -150,6 +304,94 @@ impl Command for SubCommand {
                 example: "9day | into string",
                 result: Some(Value::test_string("1wk 2day")),
             },
+            Example {
+                description: "convert range to a list of strings",
+                example: "0..3 | into string",
+                result: Some(Value::list(
+                    vec![
+                        Value::test_string("0"),
+                        Value::test_string("1"),
+                        Value::test_string("2"),
+                        Value::test_string("3"),
+                    ],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                description: "trim whitespace while converting to string",
+                example: "'  hi  ' | into string --trim",
+                result: Some(Value::test_string("hi")),
+            },
+            Example {
+                description: "right-align a number to a fixed width",
+                example: "5 | into string --pad-left 4",
+                result: Some(Value::test_string("   5")),
+            },
+            Example {
+                description: "left-align a number to a fixed width with a custom pad character",
+                example: "5 | into string --pad-right 4 --pad-char '0'",
+                result: Some(Value::test_string("5000")),
+            },
+            Example {
+                description: "round to 4 decimals, then drop the padding those decimals added",
+                example: "1.5000 | into string -d 4 --compact",
+                result: Some(Value::test_string("1.5")),
+            },
+            Example {
+                description: "round half-way values up instead of however Rust's formatter happens to land, for predictable financial rounding",
+                example: "2.5 | into string -d 0 --round-mode half-up",
+                result: Some(Value::test_string("3")),
+            },
+            Example {
+                description: "group an integer's digits into thousands",
+                example: "1234567 | into string --group-digits",
+                result: Some(Value::test_string("1,234,567")),
+            },
+            Example {
+                description: "group a float's integer part into thousands, leaving the decimal part alone",
+                example: "1234567.5 | into string -d 1 --group-digits",
+                result: Some(Value::test_string("1,234,567.5")),
+            },
+            Example {
+                description: "stringify binary data as hex instead of erroring",
+                example: "0x[DE AD BE EF] | into string --encode hex",
+                result: Some(Value::test_string("deadbeef")),
+            },
+            Example {
+                description: "stringify binary data as base64",
+                example: "0x[DE AD BE EF] | into string --encode base64",
+                result: Some(Value::test_string("3q2+7w==")),
+            },
+            Example {
+                description: "stringify binary data as uppercase hex",
+                example: "0x[DE AD BE EF] | into string --encode hex --case upper",
+                result: Some(Value::test_string("DEADBEEF")),
+            },
+            Example {
+                description: "render binary as an escaped, lossless string instead of erroring",
+                example: "0x[DE AD 41 42] | into string --escape",
+                result: Some(Value::test_string("\\xde\\xadAB".to_string())),
+            },
+            Example {
+                description: "show a placeholder for null values in a report",
+                example: "null | into string --when-empty 'N/A'",
+                result: Some(Value::test_string("N/A")),
+            },
+            Example {
+                description: "stringify a whole list as a single nuon-like string instead of one string per element",
+                example: "[a b] | into string --structured",
+                result: Some(Value::test_string("[a, b]")),
+            },
+            Example {
+                description: "join a list's elements with spaces",
+                example: "[a b] | into string --list-style space",
+                result: Some(Value::test_string("a b")),
+            },
+            Example {
+                description: "render a range as its literal notation instead of materializing its elements",
+                example: "1..10 | into string --structured",
+                result: Some(Value::test_string("1..10")),
+            },
         ]
     }
 }
@@ -171,12 +413,127 @@ fn string_helper(
             });
         }
     }
+    let pad_left: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "pad-left")?;
+    let pad_right: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "pad-right")?;
+    if let (Some(pad_left), Some(pad_right)) = (&pad_left, &pad_right) {
+        return Err(ShellError::IncompatibleParameters {
+            left_message: "--pad-left".to_string(),
+            left_span: pad_left.span,
+            right_message: "--pad-right".to_string(),
+            right_span: pad_right.span,
+        });
+    }
+    let to_width = |pad: Option<Spanned<i64>>| -> Result<Option<usize>, ShellError> {
+        match pad {
+            None => Ok(None),
+            Some(pad) if pad.item < 0 => Err(ShellError::TypeMismatch {
+                err_message: "Cannot accept a negative width".to_string(),
+                span: pad.span,
+            }),
+            Some(pad) => Ok(Some(pad.item as usize)),
+        }
+    };
+    let pad_left = to_width(pad_left)?;
+    let pad_right = to_width(pad_right)?;
+    let pad_char: Option<Spanned<String>> = call.get_flag(engine_state, stack, "pad-char")?;
+    let pad_char = match pad_char {
+        None => ' ',
+        Some(pad_char) => {
+            let mut chars = pad_char.item.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => {
+                    return Err(ShellError::TypeMismatch {
+                        err_message: "pad-char must be exactly one character".to_string(),
+                        span: pad_char.span,
+                    })
+                }
+            }
+        }
+    };
+
+    let encode: Option<Spanned<String>> = call.get_flag(engine_state, stack, "encode")?;
+    if let Some(encode) = &encode {
+        if !matches!(encode.item.as_str(), "hex" | "base64" | "base64url") {
+            return Err(ShellError::IncorrectValue {
+                msg: "encode must be one of: hex, base64, base64url".to_string(),
+                val_span: encode.span,
+                call_span: head,
+            });
+        }
+    }
+
+    let escape = call.has_flag("escape");
+    if let (Some(encode), Some(escape_arg)) = (&encode, call.get_named_arg("escape")) {
+        return Err(ShellError::IncompatibleParameters {
+            left_message: "--encode".to_string(),
+            left_span: encode.span,
+            right_message: "--escape".to_string(),
+            right_span: escape_arg.span,
+        });
+    }
+
+    let case: Option<Spanned<String>> = call.get_flag(engine_state, stack, "case")?;
+    let uppercase = match &case {
+        None => false,
+        Some(case) if case.item == "lower" => false,
+        Some(case) if case.item == "upper" => true,
+        Some(case) => {
+            return Err(ShellError::IncorrectValue {
+                msg: "case must be one of: lower, upper".to_string(),
+                val_span: case.span,
+                call_span: head,
+            })
+        }
+    };
+
+    let round_mode: Option<Spanned<String>> = call.get_flag(engine_state, stack, "round-mode")?;
+    let round_mode = match &round_mode {
+        None => RoundMode::default(),
+        Some(round_mode) => RoundMode::parse(&round_mode.item).ok_or_else(|| {
+            ShellError::IncorrectValue {
+                msg: "round-mode must be one of: half-up, half-even, trunc, ceil, floor"
+                    .to_string(),
+                val_span: round_mode.span,
+                call_span: head,
+            }
+        })?,
+    };
+
+    let when_empty: Option<Spanned<String>> = call.get_flag(engine_state, stack, "when-empty")?;
+    let when_empty = when_empty.map(|when_empty| when_empty.item);
+
+    let list_style_flag: Option<Spanned<String>> =
+        call.get_flag(engine_state, stack, "list-style")?;
+    let list_style = match &list_style_flag {
+        None => ListStyle::default(),
+        Some(style) => ListStyle::parse(&style.item).ok_or_else(|| ShellError::IncorrectValue {
+            msg: "list-style must be one of: nuon, space, newline, comma".to_string(),
+            val_span: style.span,
+            call_span: head,
+        })?,
+    };
+    let structured = call.has_flag("structured") || list_style_flag.is_some();
+
     let cell_paths = call.rest(engine_state, stack, 0)?;
     let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
     let config = engine_state.get_config().clone();
     let args = Arguments {
         decimals_value,
         decimals,
+        compact: call.has_flag("compact"),
+        round_mode,
+        group_digits: call.has_flag("group-digits"),
+        trim: call.has_flag("trim"),
+        pad_left,
+        pad_right,
+        pad_char,
+        encode,
+        escape,
+        uppercase,
+        when_empty,
+        structured,
+        list_style,
         cell_paths,
         config,
     };
@@ -193,35 +550,198 @@ fn string_helper(
             let output = stream.into_string()?;
             Ok(Value::string(output.item, head).into_pipeline_data())
         }
+        PipelineData::Value(Value::List { vals, .. }, ..) if args.structured => {
+            let elements = stringify_list_elements(&vals, &args, head)?;
+            Ok(Value::string(args.list_style.join(&elements), head).into_pipeline_data())
+        }
+        PipelineData::ListStream(stream, ..) if args.structured => {
+            let vals: Vec<Value> = stream.collect();
+            let elements = stringify_list_elements(&vals, &args, head)?;
+            Ok(Value::string(args.list_style.join(&elements), head).into_pipeline_data())
+        }
         _ => operate(action, args, input, head, engine_state.ctrlc.clone()),
     }
 }
 
+/// Stringify each element of a list the same way the per-element path does (decimals, padding,
+/// etc. all still apply), for `--structured` to join afterwards. Stops at the first element
+/// that fails to convert, surfacing that error directly instead of embedding it in the joined
+/// string.
+fn stringify_list_elements(
+    elements: &[Value],
+    args: &Arguments,
+    span: Span,
+) -> Result<Vec<String>, ShellError> {
+    elements
+        .iter()
+        .map(|val| match action(val, args, span) {
+            Value::String { val, .. } => Ok(val),
+            Value::Error { error, .. } => Err(*error),
+            other => Ok(other.into_abbreviated_string(&args.config)),
+        })
+        .collect()
+}
+
 fn action(input: &Value, args: &Arguments, span: Span) -> Value {
+    let result = action_inner(input, args, span);
+    let Value::String { val, .. } = &result else {
+        return result;
+    };
+
+    let val = if args.trim {
+        val.trim().to_string()
+    } else {
+        val.clone()
+    };
+
+    let val = match &args.when_empty {
+        Some(placeholder) if val.is_empty() => placeholder.clone(),
+        _ => val,
+    };
+
+    let val = match (args.pad_left, args.pad_right) {
+        (Some(width), _) => pad(&val, width, args.pad_char, true),
+        (_, Some(width)) => pad(&val, width, args.pad_char, false),
+        (None, None) => val,
+    };
+
+    Value::string(val, span)
+}
+
+/// Pad `val` to `width` with `pad_char`, adding the padding on the left when `left` is true
+/// (right-aligning `val`) or on the right otherwise (left-aligning `val`). Does not truncate
+/// `val` if it's already at least `width` characters wide.
+fn pad(val: &str, width: usize, pad_char: char, left: bool) -> String {
+    let len = val.chars().count();
+    if len >= width {
+        return val.to_string();
+    }
+
+    let padding: String = std::iter::repeat(pad_char).take(width - len).collect();
+    if left {
+        padding + val
+    } else {
+        val.to_string() + &padding
+    }
+}
+
+const MAX_RANGE_ELEMENTS: i64 = 100_000;
+
+/// Open-ended ranges are represented internally with `i64::MAX`/`i64::MIN` as a stand-in `to`
+/// bound (see `Range::new`), so they have to be detected that way rather than as `Value::Nothing`.
+fn range_to_strings(range: &Range, args: &Arguments, span: Span) -> Result<Value, ShellError> {
+    let is_open_ended = matches!(
+        range.to,
+        Value::Int { val: i64::MAX, .. } | Value::Int { val: i64::MIN, .. }
+    );
+    if is_open_ended {
+        return Err(ShellError::IncorrectValue {
+            msg: "cannot convert an open-ended range to a string".into(),
+            val_span: range.to.span(),
+            call_span: span,
+        });
+    }
+
+    let elements: Vec<Value> = range
+        .clone()
+        .into_range_iter(None)?
+        .take(MAX_RANGE_ELEMENTS as usize + 1)
+        .collect();
+
+    if elements.len() as i64 > MAX_RANGE_ELEMENTS {
+        return Err(ShellError::IncorrectValue {
+            msg: format!("range has more than {MAX_RANGE_ELEMENTS} elements to convert"),
+            val_span: range.to.span(),
+            call_span: span,
+        });
+    }
+
+    let strings = elements
+        .iter()
+        .map(|element| action_inner(element, args, span))
+        .collect();
+
+    Ok(Value::list(strings, span))
+}
+
+/// Reconstruct `range`'s literal notation (e.g. `1..10`, `1..<10`, `1..3..10`), instead of
+/// materializing its elements -- what `--structured` asks for, since the range's literal
+/// form is itself a valid, reparsable piece of `nuon`/nu syntax. Ranges with an explicit
+/// step render as `from..next..to` (the step is the *second* value of the sequence, matching
+/// the parser's own `from..next..to` literal syntax), omitting the `next` segment when the
+/// increment is the implicit default (`1` ascending, `-1` descending).
+fn range_to_literal_string(range: &Range, span: Span) -> Result<Value, ShellError> {
+    let from = range.from.as_int()?;
+    let incr = range.incr.as_int()?;
+    let default_incr = if incr > 0 { 1 } else { -1 };
+
+    let op = match range.inclusion {
+        RangeInclusion::Inclusive => "..",
+        RangeInclusion::RightExclusive => "..<",
+    };
+
+    let is_open_ended = matches!(
+        range.to,
+        Value::Int { val: i64::MAX, .. } | Value::Int { val: i64::MIN, .. }
+    );
+    let to = if is_open_ended {
+        String::new()
+    } else {
+        range.to.as_int()?.to_string()
+    };
+
+    let literal = if incr == default_incr {
+        format!("{from}{op}{to}")
+    } else {
+        format!("{from}..{}{op}{to}", from + incr)
+    };
+
+    Ok(Value::string(literal, span))
+}
+
+fn action_inner(input: &Value, args: &Arguments, span: Span) -> Value {
     let decimals = args.decimals;
     let digits = args.decimals_value;
     let config = &args.config;
     match input {
         Value::Int { val, .. } => {
             let decimal_value = digits.unwrap_or(0) as usize;
-            let res = format_int(*val, false, decimal_value);
+            let res = format_int(*val, args.group_digits, decimal_value);
             Value::string(res, span)
         }
         Value::Float { val, .. } => {
-            if decimals {
-                let decimal_value = digits.unwrap_or(2) as usize;
-                Value::string(format!("{val:.decimal_value$}"), span)
-            } else {
-                Value::string(val.to_string(), span)
-            }
+            let decimal_value = decimals.then(|| digits.unwrap_or(2) as usize);
+            Value::string(
+                format_float(
+                    *val,
+                    args.group_digits,
+                    decimal_value,
+                    args.compact,
+                    args.round_mode,
+                ),
+                span,
+            )
+        }
+        Value::Bool { val, .. } => {
+            let res = val.to_string();
+            let res = if args.uppercase { res.to_uppercase() } else { res };
+            Value::string(res, span)
         }
-        Value::Bool { val, .. } => Value::string(val.to_string(), span),
         Value::Date { val, .. } => Value::string(val.format("%c").to_string(), span),
         Value::String { val, .. } => Value::string(val.to_string(), span),
 
         Value::Filesize { val: _, .. } => Value::string(input.into_string(", ", config), span),
         Value::Duration { val: _, .. } => Value::string(input.into_string("", config), span),
 
+        Value::Range { val, .. } if args.structured => match range_to_literal_string(val, span) {
+            Ok(literal) => literal,
+            Err(error) => Value::error(error, span),
+        },
+        Value::Range { val, .. } => match range_to_strings(val, args, span) {
+            Ok(list) => list,
+            Err(error) => Value::error(error, span),
+        },
+
         Value::Error { error, .. } => Value::string(into_code(error).unwrap_or_default(), span),
         Value::Nothing { .. } => Value::string("".to_string(), span),
         Value::Record { .. } => Value::error(
@@ -234,15 +754,23 @@ fn action(input: &Value, args: &Arguments, span: Span) -> Value {
             },
             span,
         ),
-        Value::Binary { .. } => Value::error(
-            ShellError::CantConvert {
-                to_type: "string".into(),
-                from_type: "binary".into(),
+        Value::Binary { val, .. } => match (&args.encode, args.escape) {
+            (Some(encode), _) => {
+                Value::string(encode_binary(val, &encode.item, args.uppercase), span)
+            }
+            (None, true) => Value::string(escape_binary(val, args.uppercase), span),
+            (None, false) => Value::error(
+                ShellError::CantConvert {
+                    to_type: "string".into(),
+                    from_type: "binary".into(),
+                    span,
+                    help: Some(
+                        "try using the `decode` command, or `into string --encode hex|base64|base64url`, or `into string --escape`".into(),
+                    ),
+                },
                 span,
-                help: Some("try using the `decode` command".into()),
-            },
-            span,
-        ),
+            ),
+        },
         x => Value::error(
             ShellError::CantConvert {
                 to_type: String::from("string"),
@@ -255,6 +783,38 @@ fn action(input: &Value, args: &Arguments, span: Span) -> Value {
     }
 }
 
+/// Stringify binary data as hex (lowercase, unless `uppercase`), standard-padded base64, or
+/// URL-safe base64, per `--encode`. The encoding name is already validated before this is
+/// called. `uppercase` only affects hex; base64's alphabet is already mixed-case.
+fn encode_binary(val: &[u8], encoding: &str, uppercase: bool) -> String {
+    match encoding {
+        "hex" if uppercase => val.iter().map(|byte| format!("{byte:02X}")).collect(),
+        "hex" => val.iter().map(|byte| format!("{byte:02x}")).collect(),
+        "base64" => GeneralPurpose::new(&alphabet::STANDARD, PAD).encode(val),
+        "base64url" => GeneralPurpose::new(&alphabet::URL_SAFE, PAD).encode(val),
+        _ => unreachable!("encode is validated to be hex, base64, or base64url"),
+    }
+}
+
+/// Render binary as an escaped string: printable ASCII passes through literally, a literal
+/// backslash is escaped as `\\` (so it can't be confused for the start of an escape sequence),
+/// and every other byte becomes `\xHH`. Lossless and reversible: every byte maps to a fixed,
+/// unambiguous sequence, so unescaping `\xHH` (and `\\`) recovers the exact original bytes --
+/// unlike `--encode hex`/`--encode base64`, which don't read as text but also can't be told
+/// apart from the original shape of the data at a glance.
+fn escape_binary(val: &[u8], uppercase: bool) -> String {
+    let mut out = String::with_capacity(val.len());
+    for &byte in val {
+        match byte {
+            b'\\' => out.push_str("\\\\"),
+            0x20..=0x7E => out.push(byte as char),
+            _ if uppercase => out.push_str(&format!("\\x{byte:02X}")),
+            _ => out.push_str(&format!("\\x{byte:02x}")),
+        }
+    }
+    out
+}
+
 fn format_int(int: i64, group_digits: bool, decimals: usize) -> String {
     let locale = get_system_locale();
 
@@ -279,6 +839,134 @@ fn format_int(int: i64, group_digits: bool, decimals: usize) -> String {
     }
 }
 
+/// Format a float, applying `decimals` (if given), `compact`, and `group_digits` the same way
+/// [`format_int`] does for integers: grouping only ever touches the integer part, using the
+/// system locale's thousands separator, and leaves the fractional part (and its `.`) alone.
+fn format_float(
+    val: f64,
+    group_digits: bool,
+    decimals: Option<usize>,
+    compact: bool,
+    round_mode: RoundMode,
+) -> String {
+    let formatted = match decimals {
+        Some(decimals) => round_decimal(val, decimals, round_mode),
+        None => val.to_string(),
+    };
+    let formatted = if compact {
+        compact_decimal(&formatted)
+    } else {
+        formatted
+    };
+
+    if !group_digits {
+        return formatted;
+    }
+
+    let locale = get_system_locale();
+    match formatted.split_once('.') {
+        Some((integer_part, fractional_part)) => format!(
+            "{}.{fractional_part}",
+            group_thousands(integer_part, locale.separator())
+        ),
+        None => group_thousands(&formatted, locale.separator()),
+    }
+}
+
+/// Round `val` to `decimals` fractional digits according to `mode`, formatting the result
+/// directly from the rounded integer amount (rather than handing a rounded `f64` back to
+/// `format!("{:.n$}")`) so a later floating-point rounding pass can't undo the explicit
+/// rounding this function just did.
+fn round_decimal(val: f64, decimals: usize, mode: RoundMode) -> String {
+    if matches!(mode, RoundMode::Default) {
+        return format!("{val:.decimals$}");
+    }
+
+    let factor = 10f64.powi(decimals as i32);
+    let scaled = val * factor;
+    let rounded = match mode {
+        RoundMode::Default => unreachable!("handled above"),
+        RoundMode::HalfUp => scaled.round(),
+        RoundMode::HalfEven => round_ties_even(scaled),
+        RoundMode::Trunc => scaled.trunc(),
+        RoundMode::Ceil => scaled.ceil(),
+        RoundMode::Floor => scaled.floor(),
+    };
+    format_scaled_as_decimal(rounded, decimals)
+}
+
+/// Round half-way values to the nearest even integer instead of always rounding away from
+/// zero, the way [`f64::round`] does. Written out by hand since this repo's pinned toolchain
+/// predates the stabilization of `f64::round_ties_even`.
+fn round_ties_even(val: f64) -> f64 {
+    let floor = val.floor();
+    let diff = val - floor;
+    if diff < 0.5 {
+        floor
+    } else if diff > 0.5 {
+        floor + 1.0
+    } else if (floor as i64) % 2 == 0 {
+        floor
+    } else {
+        floor + 1.0
+    }
+}
+
+/// Render an already-rounded value (e.g. `25.0` for `2.5` kept to 1 decimal, i.e. scaled by
+/// 10^1) back into a `"<integer>.<fraction>"` string with exactly `decimals` fractional
+/// digits, working on the integer amount directly so no further floating-point rounding can
+/// perturb the digits that were just decided.
+fn format_scaled_as_decimal(scaled: f64, decimals: usize) -> String {
+    let scaled = scaled as i64;
+    let sign = if scaled < 0 { "-" } else { "" };
+    let digits = scaled.unsigned_abs().to_string();
+
+    if decimals == 0 {
+        return format!("{sign}{digits}");
+    }
+
+    let digits = format!("{digits:0>width$}", width = decimals + 1);
+    let split_at = digits.len() - decimals;
+    format!("{sign}{}.{}", &digits[..split_at], &digits[split_at..])
+}
+
+/// Strip a formatted float's trailing fractional zeros, and the decimal point itself if nothing
+/// is left after them, so `--decimals` padding like `"1.5000"` becomes `"1.5"` and `"1.0000"`
+/// becomes `"1"`. Leaves values with no decimal point, or no trailing zeros, untouched.
+fn compact_decimal(formatted: &str) -> String {
+    let Some((integer_part, fractional_part)) = formatted.split_once('.') else {
+        return formatted.to_string();
+    };
+
+    let trimmed = fractional_part.trim_end_matches('0');
+    if trimmed.is_empty() {
+        integer_part.to_string()
+    } else {
+        format!("{integer_part}.{trimmed}")
+    }
+}
+
+/// Group the digits of `integer_part` (e.g. `"1234567"`) into thousands using `separator`,
+/// preserving a leading `-` sign. Groups on the digit string directly, so it isn't limited by
+/// `i64`'s range the way `ToFormattedString` is, which matters for very large magnitudes.
+fn group_thousands(integer_part: &str, separator: &str) -> String {
+    let (sign, digits) = match integer_part.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", integer_part),
+    };
+
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+    while end > 3 {
+        groups.push(&digits[end - 3..end]);
+        end -= 3;
+    }
+    groups.push(&digits[..end]);
+    groups.reverse();
+
+    format!("{sign}{}", groups.join(separator))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -289,4 +977,520 @@ mod test {
 
         test_examples(SubCommand {})
     }
+
+    fn args(
+        decimals_value: Option<i64>,
+        pad_left: Option<usize>,
+        pad_right: Option<usize>,
+    ) -> Arguments {
+        Arguments {
+            decimals_value,
+            decimals: decimals_value.is_some(),
+            compact: false,
+            round_mode: RoundMode::default(),
+            group_digits: false,
+            trim: false,
+            pad_left,
+            pad_right,
+            pad_char: ' ',
+            encode: None,
+            escape: false,
+            uppercase: false,
+            when_empty: None,
+            structured: false,
+            list_style: ListStyle::default(),
+            cell_paths: None,
+            config: Config::default(),
+        }
+    }
+
+    #[test]
+    fn test_trim_flag() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.trim = true;
+        let actual = action(&Value::string("  hi  ".to_string(), span), &args, span);
+        assert_eq!(actual, Value::string("hi".to_string(), span));
+    }
+
+    #[test]
+    fn test_pad_left_with_decimals() {
+        let span = Span::test_data();
+        let args = args(Some(2), Some(8), None);
+        let actual = action(&Value::float(1.5, span), &args, span);
+        assert_eq!(actual, Value::string("    1.50".to_string(), span));
+    }
+
+    #[test]
+    fn test_pad_right_with_decimals() {
+        let span = Span::test_data();
+        let args = args(Some(2), None, Some(8));
+        let actual = action(&Value::float(1.5, span), &args, span);
+        assert_eq!(actual, Value::string("1.50    ".to_string(), span));
+    }
+
+    #[test]
+    fn test_pad_does_not_truncate_when_value_already_wider() {
+        let span = Span::test_data();
+        let args = args(Some(2), Some(3), None);
+        let actual = action(&Value::float(123.456, span), &args, span);
+        assert_eq!(actual, Value::string("123.46".to_string(), span));
+    }
+
+    #[test]
+    fn test_pad_char() {
+        let span = Span::test_data();
+        let mut args = args(None, None, Some(4));
+        args.pad_char = '0';
+        let actual = action(&Value::int(5, span), &args, span);
+        assert_eq!(actual, Value::string("5000".to_string(), span));
+    }
+
+    #[test]
+    fn test_small_inclusive_range_becomes_list_of_strings() {
+        use nu_protocol::ast::RangeInclusion;
+
+        let span = Span::test_data();
+        let range = Range {
+            from: Value::int(0, span),
+            incr: Value::int(1, span),
+            to: Value::int(3, span),
+            inclusion: RangeInclusion::Inclusive,
+        };
+        let args = args(None, None, None);
+        let actual = action(&Value::range(range, span), &args, span);
+        assert_eq!(
+            actual,
+            Value::list(
+                vec![
+                    Value::string("0", span),
+                    Value::string("1", span),
+                    Value::string("2", span),
+                    Value::string("3", span),
+                ],
+                span,
+            )
+        );
+    }
+
+    #[test]
+    fn test_open_ended_range_is_an_error() {
+        use nu_protocol::ast::RangeInclusion;
+
+        let span = Span::test_data();
+        let range = Range {
+            from: Value::int(0, span),
+            incr: Value::int(1, span),
+            to: Value::int(i64::MAX, span),
+            inclusion: RangeInclusion::Inclusive,
+        };
+        let args = args(None, None, None);
+        let actual = action(&Value::range(range, span), &args, span);
+        assert!(matches!(actual, Value::Error { .. }));
+    }
+
+    #[test]
+    fn test_structured_inclusive_range_renders_as_its_literal_notation() {
+        use nu_protocol::ast::RangeInclusion;
+
+        let span = Span::test_data();
+        let range = Range {
+            from: Value::int(1, span),
+            incr: Value::int(1, span),
+            to: Value::int(10, span),
+            inclusion: RangeInclusion::Inclusive,
+        };
+        let mut args = args(None, None, None);
+        args.structured = true;
+        let actual = action(&Value::range(range, span), &args, span);
+        assert_eq!(actual, Value::string("1..10", span));
+    }
+
+    #[test]
+    fn test_structured_exclusive_range_renders_with_the_exclusive_operator() {
+        use nu_protocol::ast::RangeInclusion;
+
+        let span = Span::test_data();
+        let range = Range {
+            from: Value::int(1, span),
+            incr: Value::int(1, span),
+            to: Value::int(10, span),
+            inclusion: RangeInclusion::RightExclusive,
+        };
+        let mut args = args(None, None, None);
+        args.structured = true;
+        let actual = action(&Value::range(range, span), &args, span);
+        assert_eq!(actual, Value::string("1..<10", span));
+    }
+
+    #[test]
+    fn test_structured_stepped_range_includes_the_next_segment() {
+        use nu_protocol::ast::RangeInclusion;
+
+        let span = Span::test_data();
+        let range = Range {
+            from: Value::int(0, span),
+            incr: Value::int(3, span),
+            to: Value::int(20, span),
+            inclusion: RangeInclusion::Inclusive,
+        };
+        let mut args = args(None, None, None);
+        args.structured = true;
+        let actual = action(&Value::range(range, span), &args, span);
+        assert_eq!(actual, Value::string("0..3..20", span));
+    }
+
+    #[test]
+    fn test_binary_without_encode_is_an_error() {
+        let span = Span::test_data();
+        let args = args(None, None, None);
+        let actual = action(&Value::binary(vec![0xDE, 0xAD], span), &args, span);
+        assert!(matches!(actual, Value::Error { .. }));
+    }
+
+    #[test]
+    fn test_binary_encode_hex() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.encode = Some(Spanned {
+            item: "hex".to_string(),
+            span,
+        });
+        let actual = action(
+            &Value::binary(vec![0xDE, 0xAD, 0xBE, 0xEF], span),
+            &args,
+            span,
+        );
+        assert_eq!(actual, Value::string("deadbeef".to_string(), span));
+    }
+
+    #[test]
+    fn test_binary_encode_base64() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.encode = Some(Spanned {
+            item: "base64".to_string(),
+            span,
+        });
+        let actual = action(
+            &Value::binary(vec![0xDE, 0xAD, 0xBE, 0xEF], span),
+            &args,
+            span,
+        );
+        assert_eq!(actual, Value::string("3q2+7w==".to_string(), span));
+    }
+
+    #[test]
+    fn test_escape_passes_printable_ascii_through() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.escape = true;
+        let actual = action(&Value::binary(b"AB".to_vec(), span), &args, span);
+        assert_eq!(actual, Value::string("AB".to_string(), span));
+    }
+
+    #[test]
+    fn test_escape_renders_non_printable_bytes_as_hex() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.escape = true;
+        let actual = action(&Value::binary(vec![0xDE, 0xAD], span), &args, span);
+        assert_eq!(actual, Value::string("\\xde\\xad".to_string(), span));
+    }
+
+    #[test]
+    fn test_escape_escapes_a_literal_backslash() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.escape = true;
+        let actual = action(&Value::binary(vec![0x5C], span), &args, span);
+        assert_eq!(actual, Value::string("\\\\".to_string(), span));
+    }
+
+    #[test]
+    fn test_escape_respects_uppercase_case() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.escape = true;
+        args.uppercase = true;
+        let actual = action(&Value::binary(vec![0xDE, 0xAD], span), &args, span);
+        assert_eq!(actual, Value::string("\\xDE\\xAD".to_string(), span));
+    }
+
+    #[test]
+    fn test_escape_round_trips_with_unescape() {
+        let original = vec![0xDE, 0xAD, b'A', b'B', 0x5C, 0x00];
+        let escaped = escape_binary(&original, false);
+
+        let mut bytes = Vec::new();
+        let mut chars = escaped.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                match chars.next() {
+                    Some('\\') => bytes.push(b'\\'),
+                    Some('x') => {
+                        let hi = chars.next().expect("hex digit");
+                        let lo = chars.next().expect("hex digit");
+                        let byte = u8::from_str_radix(&format!("{hi}{lo}"), 16)
+                            .expect("valid hex escape");
+                        bytes.push(byte);
+                    }
+                    other => panic!("unexpected escape: {other:?}"),
+                }
+            } else {
+                bytes.push(c as u8);
+            }
+        }
+
+        assert_eq!(bytes, original);
+    }
+
+    #[test]
+    fn test_compact_strips_trailing_zeros_added_by_decimals() {
+        let span = Span::test_data();
+        let mut args = args(Some(4), None, None);
+        args.compact = true;
+        let actual = action(&Value::float(1.5, span), &args, span);
+        assert_eq!(actual, Value::string("1.5".to_string(), span));
+    }
+
+    #[test]
+    fn test_compact_strips_the_decimal_point_when_nothing_is_left() {
+        let span = Span::test_data();
+        let mut args = args(Some(4), None, None);
+        args.compact = true;
+        let actual = action(&Value::float(1.0, span), &args, span);
+        assert_eq!(actual, Value::string("1".to_string(), span));
+    }
+
+    #[test]
+    fn test_compact_leaves_a_value_with_no_trailing_zeros_alone() {
+        let span = Span::test_data();
+        let mut args = args(Some(3), None, None);
+        args.compact = true;
+        let actual = action(&Value::float(1.734, span), &args, span);
+        assert_eq!(actual, Value::string("1.734".to_string(), span));
+    }
+
+    #[test]
+    fn test_round_mode_half_up_rounds_away_from_zero() {
+        let span = Span::test_data();
+        let mut args = args(Some(0), None, None);
+        args.round_mode = RoundMode::HalfUp;
+        let actual = action(&Value::float(2.5, span), &args, span);
+        assert_eq!(actual, Value::string("3".to_string(), span));
+    }
+
+    #[test]
+    fn test_round_mode_half_even_rounds_to_the_nearest_even_integer() {
+        let span = Span::test_data();
+        let mut args = args(Some(0), None, None);
+        args.round_mode = RoundMode::HalfEven;
+        let actual = action(&Value::float(2.5, span), &args, span);
+        assert_eq!(actual, Value::string("2".to_string(), span));
+    }
+
+    #[test]
+    fn test_round_mode_trunc_drops_the_fraction() {
+        let span = Span::test_data();
+        let mut args = args(Some(0), None, None);
+        args.round_mode = RoundMode::Trunc;
+        let actual = action(&Value::float(2.5, span), &args, span);
+        assert_eq!(actual, Value::string("2".to_string(), span));
+    }
+
+    #[test]
+    fn test_round_mode_ceil_always_rounds_up() {
+        let span = Span::test_data();
+        let mut args = args(Some(0), None, None);
+        args.round_mode = RoundMode::Ceil;
+        let actual = action(&Value::float(2.5, span), &args, span);
+        assert_eq!(actual, Value::string("3".to_string(), span));
+    }
+
+    #[test]
+    fn test_round_mode_floor_always_rounds_down() {
+        let span = Span::test_data();
+        let mut args = args(Some(0), None, None);
+        args.round_mode = RoundMode::Floor;
+        let actual = action(&Value::float(2.5, span), &args, span);
+        assert_eq!(actual, Value::string("2".to_string(), span));
+    }
+
+    #[test]
+    fn test_round_mode_trunc_on_a_negative_value_rounds_toward_zero() {
+        let span = Span::test_data();
+        let mut args = args(Some(0), None, None);
+        args.round_mode = RoundMode::Trunc;
+        let actual = action(&Value::float(-2.5, span), &args, span);
+        assert_eq!(actual, Value::string("-2".to_string(), span));
+    }
+
+    #[test]
+    fn test_round_mode_default_falls_back_to_standard_formatting() {
+        let span = Span::test_data();
+        let args = args(Some(0), None, None);
+        let actual = action(&Value::float(1.7, span), &args, span);
+        assert_eq!(actual, Value::string("2".to_string(), span));
+    }
+
+    #[test]
+    fn test_invalid_round_mode_is_an_error() {
+        let actual = nu_test_support::nu!(r#"2.5 | into string -d 0 --round-mode bogus"#);
+        assert!(actual.err.contains("round-mode"));
+    }
+
+    #[test]
+    fn test_group_digits_on_an_integer() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.group_digits = true;
+        let actual = action(&Value::int(1234567, span), &args, span);
+        assert_eq!(actual, Value::string("1,234,567".to_string(), span));
+    }
+
+    #[test]
+    fn test_group_digits_on_a_float_leaves_the_fractional_part_alone() {
+        let span = Span::test_data();
+        let mut args = args(Some(1), None, None);
+        args.group_digits = true;
+        let actual = action(&Value::float(1234567.5, span), &args, span);
+        assert_eq!(actual, Value::string("1,234,567.5".to_string(), span));
+    }
+
+    #[test]
+    fn test_group_digits_on_a_negative_float() {
+        let span = Span::test_data();
+        let mut args = args(Some(2), None, None);
+        args.group_digits = true;
+        let actual = action(&Value::float(-1234567.89, span), &args, span);
+        assert_eq!(actual, Value::string("-1,234,567.89".to_string(), span));
+    }
+
+    #[test]
+    fn test_group_digits_on_a_float_without_decimals_flag() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.group_digits = true;
+        let actual = action(&Value::float(1234567.0, span), &args, span);
+        assert_eq!(actual, Value::string("1,234,567".to_string(), span));
+    }
+
+    #[test]
+    fn test_group_thousands_handles_magnitudes_larger_than_i64() {
+        assert_eq!(
+            group_thousands("123456789012345678901234567890", ","),
+            "123,456,789,012,345,678,901,234,567,890"
+        );
+    }
+
+    #[test]
+    fn test_binary_encode_base64url() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.encode = Some(Spanned {
+            item: "base64url".to_string(),
+            span,
+        });
+        let actual = action(&Value::binary(vec![0x3E, 0x3F], span), &args, span);
+        assert_eq!(actual, Value::string("Pj8=".to_string(), span));
+    }
+
+    #[test]
+    fn test_uppercase_hex() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.encode = Some(Spanned {
+            item: "hex".to_string(),
+            span,
+        });
+        args.uppercase = true;
+        let actual = action(
+            &Value::binary(vec![0xDE, 0xAD, 0xBE, 0xEF], span),
+            &args,
+            span,
+        );
+        assert_eq!(actual, Value::string("DEADBEEF".to_string(), span));
+    }
+
+    #[test]
+    fn test_when_empty_applies_to_null() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.when_empty = Some("N/A".to_string());
+        let actual = action(&Value::nothing(span), &args, span);
+        assert_eq!(actual, Value::string("N/A".to_string(), span));
+    }
+
+    #[test]
+    fn test_when_empty_applies_to_an_already_empty_string() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.when_empty = Some("N/A".to_string());
+        let actual = action(&Value::string("".to_string(), span), &args, span);
+        assert_eq!(actual, Value::string("N/A".to_string(), span));
+    }
+
+    #[test]
+    fn test_when_empty_leaves_a_non_empty_string_alone() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.when_empty = Some("N/A".to_string());
+        let actual = action(&Value::string("hi".to_string(), span), &args, span);
+        assert_eq!(actual, Value::string("hi".to_string(), span));
+    }
+
+    #[test]
+    fn test_structured_list_defaults_to_nuon_style() {
+        let actual = nu_test_support::nu!("[a b c] | into string --structured");
+        assert_eq!(actual.out, "[a, b, c]");
+    }
+
+    #[test]
+    fn test_list_style_space() {
+        let actual = nu_test_support::nu!("[a b c] | into string --list-style space");
+        assert_eq!(actual.out, "a b c");
+    }
+
+    #[test]
+    fn test_list_style_newline() {
+        let actual = nu_test_support::nu!("[a b c] | into string --list-style newline | lines | length");
+        assert_eq!(actual.out, "3");
+    }
+
+    #[test]
+    fn test_list_style_comma() {
+        let actual = nu_test_support::nu!("[a b c] | into string --list-style comma");
+        assert_eq!(actual.out, "a, b, c");
+    }
+
+    #[test]
+    fn test_list_style_nuon_explicit() {
+        let actual = nu_test_support::nu!("[a b c] | into string --list-style nuon");
+        assert_eq!(actual.out, "[a, b, c]");
+    }
+
+    #[test]
+    fn test_invalid_list_style_is_an_error() {
+        let actual = nu_test_support::nu!("[a b c] | into string --list-style bogus");
+        assert!(actual.err.contains("list-style"));
+    }
+
+    #[test]
+    fn test_without_structured_a_list_is_still_mapped_element_wise() {
+        let actual = nu_test_support::nu!("[1 2 3] | into string | to nuon");
+        assert_eq!(actual.out, "[\"1\", \"2\", \"3\"]");
+    }
+
+    #[test]
+    fn test_uppercase_bool() {
+        let span = Span::test_data();
+        let mut args = args(None, None, None);
+        args.uppercase = true;
+        let actual = action(&Value::bool(true, span), &args, span);
+        assert_eq!(actual, Value::string("TRUE".to_string(), span));
+
+        let actual = action(&Value::bool(false, span), &args, span);
+        assert_eq!(actual, Value::string("FALSE".to_string(), span));
+    }
 }
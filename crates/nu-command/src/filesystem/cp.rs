@@ -10,8 +10,8 @@ use nu_path::{canonicalize_with, expand_path_with};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    Spanned, SyntaxShape, Type, Value,
+    record, Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 
 use super::util::try_interaction;
@@ -70,6 +70,11 @@ impl Command for Cp {
                 Some('n'),
             )
             .switch("progress", "enable progress bar", Some('p'))
+            .switch(
+                "dry-run",
+                "show the planned source/destination pairs without copying anything",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -93,6 +98,7 @@ impl Command for Cp {
         let interactive = call.has_flag("interactive");
         let progress = call.has_flag("progress");
         let update_mode = call.has_flag("update");
+        let dry_run = call.has_flag("dry-run");
 
         let current_dir_path = current_dir(engine_state, stack)?;
         let source = current_dir_path.join(src.item.as_str());
@@ -156,6 +162,7 @@ impl Command for Cp {
         }
 
         let mut result = Vec::new();
+        let mut planned = Vec::new();
 
         for entry in sources.into_iter().flatten() {
             if nu_utils::ctrl_c::was_pressed(&ctrlc) {
@@ -166,7 +173,7 @@ impl Command for Cp {
             sources.walk_decorate(&entry, engine_state, stack)?;
 
             if entry.is_file() {
-                let sources = sources.paths_applying_with(|(source_file, _depth_level)| {
+                let sources = sources.plan_paths_applying_with(|(source_file, _depth_level)| {
                     if destination.is_dir() {
                         let mut dest = canonicalize_with(&dst.item, &current_dir_path)?;
                         if let Some(name) = entry.file_name() {
@@ -188,6 +195,11 @@ impl Command for Cp {
                             continue;
                         }
 
+                        if dry_run {
+                            planned.push(planned_pair(&src, &dst, span));
+                            continue;
+                        }
+
                         let res = if src == dst {
                             let message = format!(
                                 "src {source:?} and dst {destination:?} are identical(not copied)"
@@ -242,18 +254,20 @@ impl Command for Cp {
                     }
                 };
 
-                std::fs::create_dir_all(&destination).map_err(|e| {
-                    ShellError::GenericError(
-                        e.to_string(),
-                        e.to_string(),
-                        Some(dst.span),
-                        None,
-                        Vec::new(),
-                    )
-                })?;
+                if !dry_run {
+                    std::fs::create_dir_all(&destination).map_err(|e| {
+                        ShellError::GenericError(
+                            e.to_string(),
+                            e.to_string(),
+                            Some(dst.span),
+                            None,
+                            Vec::new(),
+                        )
+                    })?;
+                }
 
                 let not_follow_symlink = call.has_flag("no-symlink");
-                let sources = sources.paths_applying_with(|(source_file, depth_level)| {
+                let sources = sources.plan_paths_applying_with(|(source_file, depth_level)| {
                     let mut dest = destination.clone();
 
                     let path = if not_follow_symlink {
@@ -291,6 +305,13 @@ impl Command for Cp {
                         return Ok(PipelineData::empty());
                     }
 
+                    if dry_run {
+                        if s.is_file() || s.is_symlink() {
+                            planned.push(planned_pair(&s, &d, span));
+                        }
+                        continue;
+                    }
+
                     if s.is_dir() && !d.exists() {
                         std::fs::create_dir_all(&d).map_err(|e| {
                             ShellError::GenericError(
@@ -334,6 +355,10 @@ impl Command for Cp {
             }
         }
 
+        if dry_run {
+            return Ok(planned.into_iter().into_pipeline_data(ctrlc));
+        }
+
         if verbose {
             result
                 .into_iter()
@@ -377,10 +402,26 @@ impl Command for Cp {
                 example: "cp -u a b",
                 result: None,
             },
+            Example {
+                description: "Preview the source/destination pairs a recursive copy would produce, without copying anything",
+                example: "cp -r --dry-run dir_a dir_b",
+                result: None,
+            },
         ]
     }
 }
 
+/// A single `--dry-run` row: the source/destination pair a real copy would have used.
+fn planned_pair(src: &std::path::Path, dst: &std::path::Path, span: Span) -> Value {
+    Value::record(
+        record! {
+            "source" => Value::string(src.to_string_lossy(), span),
+            "destination" => Value::string(dst.to_string_lossy(), span),
+        },
+        span,
+    )
+}
+
 fn interactive_copy(
     interactive: bool,
     src: PathBuf,
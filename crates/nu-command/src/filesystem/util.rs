@@ -1,21 +1,69 @@
+use std::collections::HashSet;
+use std::io;
 use std::path::{Path, PathBuf};
 
 use nu_engine::env::current_dir_str;
+use nu_glob::Pattern;
 use nu_path::canonicalize_with;
 use nu_protocol::engine::{EngineState, Stack};
 use nu_protocol::ShellError;
 
 use dialoguer::Input;
+use rayon::prelude::*;
 use std::error::Error;
 
 #[derive(Default)]
 pub struct FileStructure {
     pub resources: Vec<Resource>,
+    /// Per-path read errors collected while walking, when `continue_on_error` is set.
+    /// Each entry is the directory that couldn't be fully read and the error encountered.
+    pub errors: Vec<(PathBuf, io::Error)>,
+    follow_symlinks: bool,
+    continue_on_error: bool,
+    visited: HashSet<PathBuf>,
+    filter: Option<Pattern>,
 }
 
 impl FileStructure {
     pub fn new() -> FileStructure {
-        FileStructure { resources: vec![] }
+        FileStructure {
+            resources: vec![],
+            errors: vec![],
+            follow_symlinks: false,
+            continue_on_error: false,
+            visited: HashSet::new(),
+            filter: None,
+        }
+    }
+
+    /// Controls whether directory symlinks are descended into while walking.
+    ///
+    /// Defaults to `false`: symlinks are recorded as leaf resources instead of being
+    /// followed, which avoids infinite recursion on symlink loops.
+    pub fn follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
+
+    /// Controls whether an unreadable directory aborts the whole walk or is skipped.
+    ///
+    /// Defaults to `false`: the first `read_dir` or directory entry error aborts
+    /// `walk_decorate`, matching the historical behavior. When `true`, such errors are
+    /// pushed onto `errors` instead, and the walk continues into sibling paths.
+    pub fn continue_on_error(mut self, continue_on_error: bool) -> Self {
+        self.continue_on_error = continue_on_error;
+        self
+    }
+
+    /// Restrict the walk's collected *file* resources to those matching `pattern`, so a
+    /// selective copy/move doesn't have to collect every file in a large tree just to filter
+    /// it back down afterwards. Directories are always collected regardless of `pattern`, since
+    /// callers generally need them to recreate the tree's structure at a destination.
+    ///
+    /// Defaults to `None`: every file is collected, matching the historical behavior.
+    pub fn filter(mut self, pattern: Option<Pattern>) -> Self {
+        self.filter = pattern;
+        self
     }
 
     pub fn paths_applying_with<F>(
@@ -32,6 +80,64 @@ impl FileStructure {
             .collect()
     }
 
+    /// Plan the `(source, destination)` pairs `to` would produce without touching the
+    /// filesystem, so a `--dry-run` flag on a copy/move-style command can preview them.
+    ///
+    /// This is just [`paths_applying_with`](Self::paths_applying_with) under a name that makes
+    /// the dry-run intent explicit at call sites; `to` itself must stick to path arithmetic (no
+    /// writes) for the preview to actually be dry.
+    pub fn plan_paths_applying_with<F>(
+        &mut self,
+        to: F,
+    ) -> Result<Vec<(PathBuf, PathBuf)>, Box<dyn std::error::Error>>
+    where
+        F: Fn((PathBuf, usize)) -> Result<(PathBuf, PathBuf), Box<dyn std::error::Error>>,
+    {
+        self.paths_applying_with(to)
+    }
+
+    /// Same as [`paths_applying_with`](Self::paths_applying_with), but maps `to` over the
+    /// resources in parallel with rayon, preserving output order.
+    ///
+    /// Only worth reaching for when `to` does real I/O (e.g. canonicalization) and the tree
+    /// is large enough for that to matter; callers that need deterministic ordering of side
+    /// effects (not just the returned order) should keep using the sequential version.
+    pub fn paths_applying_with_parallel<F>(
+        &mut self,
+        to: F,
+    ) -> Result<Vec<(PathBuf, PathBuf)>, Box<dyn std::error::Error + Send + Sync>>
+    where
+        F: Fn((PathBuf, usize)) -> Result<(PathBuf, PathBuf), Box<dyn std::error::Error + Send + Sync>>
+            + Send
+            + Sync,
+    {
+        self.resources
+            .par_iter()
+            .map(|f| (PathBuf::from(&f.location), f.at))
+            .map(to)
+            .collect()
+    }
+
+    /// Return each resource's location relative to `base`, paired with its depth.
+    ///
+    /// Centralizes the prefix-stripping that copy/move-style commands would otherwise
+    /// repeat by hand when turning an absolute walked path into a destination-relative one.
+    /// A resource whose `location` doesn't actually start with `base` is returned unchanged,
+    /// so callers can pass an un-canonicalized `base` without risking a panic.
+    pub fn relative_to(&self, base: &Path) -> Vec<(PathBuf, usize)> {
+        self.resources
+            .iter()
+            .map(|resource| {
+                let rel = resource
+                    .location
+                    .strip_prefix(base)
+                    .map(Path::to_path_buf)
+                    .unwrap_or_else(|_| resource.location.clone());
+                (rel, resource.at)
+            })
+            .collect()
+    }
+
     pub fn walk_decorate(
         &mut self,
         start_path: &Path,
@@ -39,6 +145,8 @@ impl FileStructure {
         stack: &Stack,
     ) -> Result<(), ShellError> {
         self.resources = Vec::<Resource>::new();
+        self.errors = Vec::new();
+        self.visited.clear();
         self.build(start_path, 0, engine_state, stack)?;
         self.resources.sort();
 
@@ -54,21 +162,49 @@ impl FileStructure {
     ) -> Result<(), ShellError> {
         let source = canonicalize_with(src, current_dir_str(engine_state, stack)?)?;
 
+        if self.follow_symlinks && !self.visited.insert(source.clone()) {
+            // Already visited this canonical path: a symlink cycle, stop descending.
+            self.resources.push(Resource {
+                location: source,
+                at: lvl,
+            });
+            return Ok(());
+        }
+
         if source.is_dir() {
-            for entry in std::fs::read_dir(src)? {
-                let entry = entry?;
+            let read_dir = match std::fs::read_dir(src) {
+                Ok(read_dir) => read_dir,
+                Err(err) if self.continue_on_error => {
+                    self.errors.push((source, err));
+                    return Ok(());
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            for entry in read_dir {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(err) if self.continue_on_error => {
+                        self.errors.push((source.clone(), err));
+                        continue;
+                    }
+                    Err(err) => return Err(err.into()),
+                };
                 let path = entry.path();
+                let is_symlink = path.is_symlink();
 
-                if path.is_dir() {
+                if path.is_dir() && (self.follow_symlinks || !is_symlink) {
                     self.build(&path, lvl + 1, engine_state, stack)?;
                 }
 
-                self.resources.push(Resource {
-                    location: path.to_path_buf(),
-                    at: lvl,
-                });
+                if self.matches_filter(&path) {
+                    self.resources.push(Resource {
+                        location: path.to_path_buf(),
+                        at: lvl,
+                    });
+                }
             }
-        } else {
+        } else if self.matches_filter(&source) {
             self.resources.push(Resource {
                 location: source,
                 at: lvl,
@@ -77,6 +213,16 @@ impl FileStructure {
 
         Ok(())
     }
+
+    /// A directory always passes, since callers generally need every directory to recreate the
+    /// tree's structure at a destination; only files are checked against `filter`.
+    fn matches_filter(&self, path: &Path) -> bool {
+        path.is_dir()
+            || self
+                .filter
+                .as_ref()
+                .map_or(true, |pattern| pattern.matches_path(path))
+    }
 }
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -163,6 +309,270 @@ pub fn is_older(src: &Path, dst: &Path) -> Option<bool> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::engine::{EngineState, Stack};
+
+    #[cfg(unix)]
+    #[test]
+    fn does_not_recurse_into_a_self_referential_symlink() {
+        use nu_test_support::playground::Playground;
+
+        Playground::setup("file_structure_symlink_loop", |dirs, _| {
+            let loop_link = dirs.test().join("loop");
+            std::os::unix::fs::symlink(dirs.test(), &loop_link)
+                .expect("failed to create symlink");
+
+            let engine_state = EngineState::new();
+            let stack = Stack::new();
+
+            let mut structure = FileStructure::new();
+            structure
+                .walk_decorate(dirs.test(), &engine_state, &stack)
+                .expect("walk_decorate should not recurse infinitely");
+
+            assert!(structure
+                .resources
+                .iter()
+                .any(|resource| resource.location == loop_link));
+        });
+    }
+
+    #[test]
+    fn reusing_a_structure_across_two_walks_does_not_corrupt_cycle_detection() {
+        use nu_test_support::playground::Playground;
+
+        Playground::setup("file_structure_reused_across_walks", |dirs, sandbox| {
+            sandbox
+                .mkdir("a")
+                .with_files(vec![nu_test_support::fs::Stub::EmptyFile("a/one.txt")]);
+
+            let engine_state = EngineState::new();
+            let stack = Stack::new();
+
+            // `follow_symlinks(true)` makes `build` record every path it visits in `visited`,
+            // not just symlinks, so walking the same directory a second time with a reused
+            // `structure` exercises the leftover-state bug: if `visited` wasn't cleared between
+            // calls, `start_path` would already be in it from the first walk, and the second
+            // walk would wrongly treat its own root as an already-visited cycle and stop
+            // without descending into it at all.
+            let mut structure = FileStructure::new().follow_symlinks(true);
+            structure
+                .walk_decorate(dirs.test(), &engine_state, &stack)
+                .expect("first walk should succeed");
+
+            structure
+                .walk_decorate(dirs.test(), &engine_state, &stack)
+                .expect("second walk on a reused structure should succeed");
+
+            assert!(structure
+                .resources
+                .iter()
+                .any(|resource| resource.location.ends_with("one.txt")));
+        });
+    }
+
+    #[test]
+    fn parallel_and_sequential_paths_applying_with_agree() {
+        use nu_test_support::playground::Playground;
+
+        Playground::setup("file_structure_parallel", |dirs, sandbox| {
+            sandbox
+                .mkdir("a")
+                .mkdir("b")
+                .with_files(vec![
+                    nu_test_support::fs::Stub::EmptyFile("a/one.txt"),
+                    nu_test_support::fs::Stub::EmptyFile("a/two.txt"),
+                    nu_test_support::fs::Stub::EmptyFile("b/three.txt"),
+                ]);
+
+            let engine_state = EngineState::new();
+            let stack = Stack::new();
+
+            let mut sequential = FileStructure::new();
+            sequential
+                .walk_decorate(dirs.test(), &engine_state, &stack)
+                .expect("walk_decorate should succeed");
+            let mut parallel = FileStructure::new();
+            parallel
+                .walk_decorate(dirs.test(), &engine_state, &stack)
+                .expect("walk_decorate should succeed");
+
+            let sequential_result = sequential
+                .paths_applying_with(|(path, depth)| Ok((path, PathBuf::from(depth.to_string()))))
+                .expect("sequential mapping should succeed");
+            let parallel_result = parallel
+                .paths_applying_with_parallel(|(path, depth)| {
+                    Ok((path, PathBuf::from(depth.to_string())))
+                })
+                .expect("parallel mapping should succeed");
+
+            assert_eq!(sequential_result, parallel_result);
+        });
+    }
+
+    #[test]
+    fn plan_paths_applying_with_does_not_touch_disk() {
+        use nu_test_support::playground::Playground;
+
+        Playground::setup("file_structure_plan", |dirs, sandbox| {
+            sandbox.mkdir("src").with_files(vec![
+                nu_test_support::fs::Stub::EmptyFile("src/one.txt"),
+                nu_test_support::fs::Stub::EmptyFile("src/two.txt"),
+            ]);
+
+            let engine_state = EngineState::new();
+            let stack = Stack::new();
+
+            let src_dir = dirs.test().join("src");
+            let dst_dir = dirs.test().join("dst");
+            assert!(!dst_dir.exists());
+
+            let mut structure = FileStructure::new();
+            structure
+                .walk_decorate(&src_dir, &engine_state, &stack)
+                .expect("walk_decorate should succeed");
+
+            let planned = structure
+                .plan_paths_applying_with(|(path, _depth)| {
+                    let name = path.file_name().expect("resource has a file name");
+                    Ok((path, dst_dir.join(name)))
+                })
+                .expect("planning should succeed");
+
+            let mut planned_names: Vec<_> = planned
+                .iter()
+                .map(|(_, dst)| dst.file_name().unwrap().to_string_lossy().to_string())
+                .collect();
+            planned_names.sort();
+            assert_eq!(planned_names, vec!["one.txt", "two.txt"]);
+
+            // The plan only computed destinations; nothing was actually created.
+            assert!(!dst_dir.exists());
+        });
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn continue_on_error_skips_an_unreadable_directory_but_still_walks_its_siblings() {
+        use nu_test_support::playground::Playground;
+        use std::os::unix::fs::PermissionsExt;
+
+        if nix::unistd::Uid::current().is_root() {
+            // root bypasses directory permission checks, so this can't be reproduced.
+            return;
+        }
+
+        Playground::setup("file_structure_unreadable_dir", |dirs, sandbox| {
+            sandbox
+                .mkdir("unreadable")
+                .mkdir("readable")
+                .with_files(vec![
+                    nu_test_support::fs::Stub::EmptyFile("unreadable/secret.txt"),
+                    nu_test_support::fs::Stub::EmptyFile("readable/visible.txt"),
+                ]);
+
+            let unreadable_dir = dirs.test().join("unreadable");
+            std::fs::set_permissions(&unreadable_dir, std::fs::Permissions::from_mode(0o000))
+                .expect("failed to strip permissions");
+
+            let engine_state = EngineState::new();
+            let stack = Stack::new();
+
+            let mut structure = FileStructure::new().continue_on_error(true);
+            let result = structure.walk_decorate(dirs.test(), &engine_state, &stack);
+
+            // Restore permissions before asserting, so the playground can clean up either way.
+            std::fs::set_permissions(&unreadable_dir, std::fs::Permissions::from_mode(0o700))
+                .expect("failed to restore permissions");
+
+            result.expect("walk_decorate should not abort when continue_on_error is set");
+
+            assert!(!structure.errors.is_empty());
+            assert!(structure
+                .resources
+                .iter()
+                .any(|resource| resource.location.ends_with("visible.txt")));
+        });
+    }
+
+    #[test]
+    fn filter_restricts_collected_files_but_keeps_directories() {
+        use nu_test_support::playground::Playground;
+
+        Playground::setup("file_structure_filter", |dirs, sandbox| {
+            sandbox.mkdir("a").with_files(vec![
+                nu_test_support::fs::Stub::EmptyFile("a/one.txt"),
+                nu_test_support::fs::Stub::EmptyFile("a/two.log"),
+                nu_test_support::fs::Stub::EmptyFile("three.txt"),
+                nu_test_support::fs::Stub::EmptyFile("four.log"),
+            ]);
+
+            let engine_state = EngineState::new();
+            let stack = Stack::new();
+
+            let mut structure = FileStructure::new().filter(Some(
+                Pattern::new("*.txt").expect("valid glob pattern"),
+            ));
+            structure
+                .walk_decorate(dirs.test(), &engine_state, &stack)
+                .expect("walk_decorate should succeed");
+
+            let mut names: Vec<_> = structure
+                .resources
+                .iter()
+                .map(|resource| {
+                    resource
+                        .location
+                        .file_name()
+                        .expect("resource has a file name")
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect();
+            names.sort();
+
+            // `a` (the directory) is kept even though it doesn't match `*.txt`; `two.log` and
+            // `four.log` are filtered out.
+            assert_eq!(names, vec!["a", "one.txt", "three.txt"]);
+        });
+    }
+
+    #[test]
+    fn relative_to_strips_the_walk_root_prefix() {
+        use nu_test_support::playground::Playground;
+
+        Playground::setup("file_structure_relative_to", |dirs, sandbox| {
+            sandbox.mkdir("a").with_files(vec![
+                nu_test_support::fs::Stub::EmptyFile("a/one.txt"),
+                nu_test_support::fs::Stub::EmptyFile("a/two.txt"),
+            ]);
+
+            let engine_state = EngineState::new();
+            let stack = Stack::new();
+
+            let src_dir = dirs.test().join("a");
+            let mut structure = FileStructure::new();
+            structure
+                .walk_decorate(&src_dir, &engine_state, &stack)
+                .expect("walk_decorate should succeed");
+
+            let canonical_root =
+                nu_path::canonicalize_with(&src_dir, dirs.test()).expect("root should resolve");
+
+            let mut relative_names: Vec<_> = structure
+                .relative_to(&canonical_root)
+                .into_iter()
+                .map(|(rel, _depth)| rel.to_string_lossy().to_string())
+                .collect();
+            relative_names.sort();
+
+            assert_eq!(relative_names, vec!["one.txt", "two.txt"]);
+        });
+    }
+}
+
 #[cfg(unix)]
 pub mod users {
     use libc::{c_int, gid_t, uid_t};
@@ -1,10 +1,17 @@
+use indexmap::IndexMap;
+use nu_cmd_base::util::process_range;
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
-use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Type,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, Range,
+    Record, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 use rand::prelude::SliceRandom;
-use rand::thread_rng;
+use rand::rngs::StdRng;
+use rand::{thread_rng, RngCore, SeedableRng};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 #[derive(Clone)]
 pub struct Shuffle;
@@ -16,38 +23,789 @@ impl Command for Shuffle {
 
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("shuffle")
-            .input_output_types(vec![(
-                Type::List(Box::new(Type::Any)),
-                Type::List(Box::new(Type::Any)),
-            )])
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::Record(vec![]), Type::Record(vec![])),
+            ])
+            .named(
+                "range",
+                SyntaxShape::Range,
+                "only shuffle the elements within this index range, leaving the rest in place (out-of-bounds ranges clamp to the list length)",
+                Some('r'),
+            )
+            .named(
+                "stable-groups-by",
+                SyntaxShape::String,
+                "group rows by this column, shuffle within each group, then concatenate the groups back in their original first-appearance order (table input only)",
+                Some('g'),
+            )
+            .named(
+                "by",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "order rows by the hash of this per-row closure's output instead of real randomness; the same input always produces the same order, useful for reproducible load distribution",
+                Some('b'),
+            )
+            .named(
+                "chunks",
+                SyntaxShape::Int,
+                "split the input into consecutive chunks of this size and shuffle the chunks' order, preserving order within each chunk",
+                Some('c'),
+            )
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "seed the random shuffle for reproducible output, instead of using real randomness",
+                Some('s'),
+            )
+            .named(
+                "rng-key",
+                SyntaxShape::String,
+                "derive the seed from this string instead of a literal --seed, so independent pipeline stages can each pick a memorable name (e.g. \"players\", \"cards\") and still shuffle reproducibly and independently of each other. The seed is the FNV-1a hash of the string (see `derive_seed_from_key`'s doc comment); this is a fixed, documented algorithm, not Rust's `DefaultHasher` (whose algorithm isn't guaranteed stable across versions), so the same key keeps producing the same order across nu versions. Incompatible with --seed",
+                None,
+            )
+            .switch(
+                "values-only",
+                "for record input, keep the key order fixed and only permute the values among the keys; without this, whole key-value pairs are shuffled together, which can reorder the keys too (record input only)",
+                Some('v'),
+            )
+            .switch(
+                "null-delimited",
+                "for raw external stdout input, split records on NUL bytes instead of newlines before shuffling",
+                Some('z'),
+            )
+            .switch(
+                "into-record",
+                "return {count: n, items: [...]} instead of the bare shuffled list, so the count can be threaded downstream in one pass (list input only)",
+                None,
+            )
             .category(Category::Filters)
     }
 
     fn usage(&self) -> &str {
-        "Shuffle rows randomly."
+        "Shuffle rows randomly. For a record, whole key-value pairs are shuffled together by default (the keys' order can move too); pass --values-only to keep the keys in place and only permute the values. Raw external stdout input (e.g. piped from an external command) is first split into lines, or on NUL bytes with --null-delimited, before shuffling."
     }
 
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let metadata = input.metadata();
+        let null_delimited = call.has_flag("null-delimited");
+        let input = buffer_external_stream_into_records(input, null_delimited, call.head)?;
+        let range: Option<Range> = call.get_flag(engine_state, stack, "range")?;
+        let stable_groups_by: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "stable-groups-by")?;
+        let by: Option<Closure> = call.get_flag(engine_state, stack, "by")?;
+        let by_span = call.get_flag_expr("by").map(|e| e.span);
+        let chunks: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "chunks")?;
+        let seed: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "seed")?;
+        let rng_key: Option<Spanned<String>> = call.get_flag(engine_state, stack, "rng-key")?;
+        let into_record = call.has_flag("into-record");
+
+        if let (Some(seed), Some(rng_key)) = (&seed, &rng_key) {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--seed".to_string(),
+                left_span: seed.span,
+                right_message: "--rng-key".to_string(),
+                right_span: rng_key.span,
+            });
+        }
+
+        let seed = seed
+            .map(|seed| seed.item as u64)
+            .or_else(|| rng_key.map(|key| derive_seed_from_key(&key.item)));
+        let mut rng = make_rng(seed);
+        let values_only = call.has_flag("values-only");
+
+        if let (Some(_), Some(by_span)) = (&range, by_span) {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--by".to_string(),
+                left_span: by_span,
+                right_message: "--range".to_string(),
+                right_span: range.as_ref().expect("checked above").from.span(),
+            });
+        }
+        if let (Some(column), Some(by_span)) = (&stable_groups_by, by_span) {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--by".to_string(),
+                left_span: by_span,
+                right_message: "--stable-groups-by".to_string(),
+                right_span: column.span,
+            });
+        }
+        if let (Some(chunks), Some(by_span)) = (&chunks, by_span) {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--by".to_string(),
+                left_span: by_span,
+                right_message: "--chunks".to_string(),
+                right_span: chunks.span,
+            });
+        }
+        if let (Some(chunks), Some(range)) = (&chunks, &range) {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--chunks".to_string(),
+                left_span: chunks.span,
+                right_message: "--range".to_string(),
+                right_span: range.from.span(),
+            });
+        }
+        if let (Some(chunks), Some(column)) = (&chunks, &stable_groups_by) {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--chunks".to_string(),
+                left_span: chunks.span,
+                right_message: "--stable-groups-by".to_string(),
+                right_span: column.span,
+            });
+        }
+
+        if let Some(chunks) = &chunks {
+            if chunks.item <= 0 {
+                return Err(ShellError::IncorrectValue {
+                    msg: "chunk size must be a positive integer".to_string(),
+                    val_span: chunks.span,
+                    call_span: call.head,
+                });
+            }
+
+            let v: Vec<_> = input.into_iter_strict(call.head)?.collect();
+            let v = shuffle_chunks(v, chunks.item as usize, rng.as_mut());
+            return Ok(
+                finish_list(v, into_record, call.head, engine_state.ctrlc.clone())
+                    .set_metadata(metadata),
+            );
+        }
+
+        if let Some(closure) = by {
+            let v: Vec<_> = input.into_iter_strict(call.head)?.collect();
+            let v = shuffle_by_key(engine_state, stack, closure, v, call.head)?;
+            return Ok(
+                finish_list(v, into_record, call.head, engine_state.ctrlc.clone())
+                    .set_metadata(metadata),
+            );
+        }
+
+        if let Some(column) = &stable_groups_by {
+            if let Some(range) = &range {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: "--stable-groups-by".to_string(),
+                    left_span: column.span,
+                    right_message: "--range".to_string(),
+                    right_span: range.from.span(),
+                });
+            }
+
+            if let PipelineData::Value(Value::Record { .. }, ..) = &input {
+                let span = input.span().unwrap_or(call.head);
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "table".into(),
+                    wrong_type: "record".into(),
+                    dst_span: call.head,
+                    src_span: span,
+                });
+            }
+
+            let v: Vec<_> = input.into_iter_strict(call.head)?.collect();
+            let v = shuffle_stable_groups(v, column, rng.as_mut())?;
+            return Ok(
+                finish_list(v, into_record, call.head, engine_state.ctrlc.clone())
+                    .set_metadata(metadata),
+            );
+        }
+
+        if let PipelineData::Value(Value::Record { val: record, .. }, ..) = &input {
+            if into_record {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "list".into(),
+                    wrong_type: "record".into(),
+                    dst_span: call.head,
+                    src_span: input.span().unwrap_or(call.head),
+                });
+            }
+
+            let span = input.span().unwrap_or(call.head);
+            let record = shuffle_record(
+                record.clone(),
+                values_only,
+                range.as_ref(),
+                call.head,
+                rng.as_mut(),
+            )?;
+            return Ok(Value::record(record, span)
+                .into_pipeline_data()
+                .set_metadata(metadata));
+        }
+
+        if let PipelineData::Value(Value::LazyRecord { val: lazy, .. }, ..) = &input {
+            if into_record {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "list".into(),
+                    wrong_type: "record".into(),
+                    dst_span: call.head,
+                    src_span: input.span().unwrap_or(call.head),
+                });
+            }
+
+            let span = input.span().unwrap_or(call.head);
+            let Value::Record { val: record, .. } = lazy.collect()? else {
+                return Err(ShellError::NushellFailed {
+                    msg: "LazyRecord::collect should always produce a Value::Record".into(),
+                });
+            };
+            let record = shuffle_record(record, values_only, range.as_ref(), call.head, rng.as_mut())?;
+            return Ok(Value::record(record, span)
+                .into_pipeline_data()
+                .set_metadata(metadata));
+        }
+
+        if values_only {
+            return Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "record".into(),
+                wrong_type: "list".into(),
+                dst_span: call.head,
+                src_span: input.span().unwrap_or(call.head),
+            });
+        }
+
         let mut v: Vec<_> = input.into_iter_strict(call.head)?.collect();
-        v.shuffle(&mut thread_rng());
-        let iter = v.into_iter();
-        Ok(iter
-            .into_pipeline_data(engine_state.ctrlc.clone())
-            .set_metadata(metadata))
+        shuffle_slice(&mut v, range.as_ref(), call.head, rng.as_mut())?;
+        Ok(finish_list(v, into_record, call.head, engine_state.ctrlc.clone()).set_metadata(metadata))
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Shuffle rows randomly (execute it several times and see the difference)",
-            example: r#"[[version patch]; ['1.0.0' false] ['3.0.1' true] ['2.0.0' false]] | shuffle"#,
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Shuffle rows randomly (execute it several times and see the difference)",
+                example: r#"[[version patch]; ['1.0.0' false] ['3.0.1' true] ['2.0.0' false]] | shuffle"#,
+                result: None,
+            },
+            Example {
+                description: "Shuffle a record's key-value pairs (the keys' display order can move too)",
+                example: "{a: 1, b: 2, c: 3} | shuffle",
+                result: None,
+            },
+            Example {
+                description: "Shuffle a record's values while keeping its keys in place",
+                example: "{a: 1, b: 2, c: 3} | shuffle --values-only",
+                result: None,
+            },
+            Example {
+                description: "Shuffle only the first two elements, leaving the rest untouched",
+                example: "[1 2 3 4 5] | shuffle --range 0..1",
+                result: None,
+            },
+            Example {
+                description: "Shuffle within each category, keeping the categories' blocks together and in their original order",
+                example: "[[id category]; [1 a] [2 a] [3 b] [4 b]] | shuffle --stable-groups-by category",
+                result: None,
+            },
+            Example {
+                description: "Order rows by the hash of a derived key instead of real randomness, so repeated runs agree",
+                example: "[[id name]; [1 alice] [2 bob] [3 carol]] | shuffle --by {|row| $row.name}",
+                result: None,
+            },
+            Example {
+                description: "Shuffle the order of fixed-size chunks, preserving order within each chunk",
+                example: "[1 2 3 4 5 6] | shuffle --chunks 2",
+                result: None,
+            },
+            Example {
+                description: "Seed the shuffle for reproducible output",
+                example: "[1 2 3 4 5] | shuffle --seed 42",
+                result: None,
+            },
+            Example {
+                description: "Derive the seed from a memorable name instead of a literal seed, so independent pipeline stages shuffle reproducibly without coordinating on a shared number",
+                example: "[1 2 3 4 5] | shuffle --rng-key players",
+                result: None,
+            },
+            Example {
+                description: "Shuffle the lines of an external command's output",
+                example: "^cat file.txt | shuffle",
+                result: None,
+            },
+            Example {
+                description: "Get both the shuffled items and their count in one pass",
+                example: "[1 2 3] | shuffle --into-record | get count",
+                result: Some(Value::test_int(3)),
+            },
+        ]
+    }
+}
+
+/// `shuffle` needs all its elements in memory anyway, so for external stdout input (raw bytes
+/// piped from an external command) this buffers the whole stream and splits it into records --
+/// lines by default, or NUL-delimited with `--null-delimited` -- before any shuffle mode sees
+/// it. Any other `PipelineData` variant passes through unchanged.
+fn buffer_external_stream_into_records(
+    input: PipelineData,
+    null_delimited: bool,
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    match input {
+        PipelineData::ExternalStream { stdout: None, .. } => {
+            Ok(Value::list(Vec::new(), head).into_pipeline_data())
+        }
+        PipelineData::ExternalStream {
+            stdout: Some(stream),
+            ..
+        } => {
+            let text = stream.into_string()?.item;
+            let delimiter = if null_delimited { '\0' } else { '\n' };
+
+            let mut records: Vec<&str> = text.split(delimiter).collect();
+            // Drop the trailing empty record left by a delimiter at the very end of the input.
+            if matches!(records.last(), Some(last) if last.is_empty()) {
+                records.pop();
+            }
+
+            let vals = records
+                .into_iter()
+                .map(|record| {
+                    let record = if null_delimited {
+                        record
+                    } else {
+                        record.trim_end_matches('\r')
+                    };
+                    Value::string(record, head)
+                })
+                .collect();
+            Ok(Value::list(vals, head).into_pipeline_data())
+        }
+        other => Ok(other),
+    }
+}
+
+/// Wrap a shuffled list for output, honoring `--into-record`: by default the bare list,
+/// or with `--into-record` a `{count, items}` record so a pipeline can use the count
+/// without a separate `length` pass over a clone.
+fn finish_list(
+    vals: Vec<Value>,
+    into_record: bool,
+    head: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> PipelineData {
+    if into_record {
+        let count = vals.len() as i64;
+        Value::record(
+            Record {
+                cols: vec!["count".to_string(), "items".to_string()],
+                vals: vec![Value::int(count, head), Value::list(vals, head)],
+            },
+            head,
+        )
+        .into_pipeline_data()
+    } else {
+        vals.into_iter().into_pipeline_data(ctrlc)
+    }
+}
+
+/// A seeded RNG makes the shuffle reproducible; without one, fall back to real randomness.
+fn make_rng(seed: Option<u64>) -> Box<dyn RngCore> {
+    match seed {
+        Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
+        None => Box::new(thread_rng()),
+    }
+}
+
+/// Derive a seed from `key` using FNV-1a, so `--rng-key` is reproducible across nu versions.
+///
+/// This is a fixed, documented algorithm chosen deliberately instead of reusing
+/// [`DefaultHasher`] (as `shuffle_by_key` does for `--by`): the standard library only
+/// guarantees `DefaultHasher`'s algorithm is stable *within* a Rust version, not across
+/// them, which would silently break `--rng-key`'s reproducibility on a toolchain upgrade.
+fn derive_seed_from_key(key: &str) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in key.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Shuffle `vals` in place, restricted to `range` if given (clamped to `vals`'s bounds);
+/// shuffles the whole slice when `range` is `None`.
+fn shuffle_slice<T>(
+    vals: &mut [T],
+    range: Option<&Range>,
+    head: Span,
+    rng: &mut dyn RngCore,
+) -> Result<(), ShellError> {
+    let Some(range) = range else {
+        vals.shuffle(rng);
+        return Ok(());
+    };
+
+    let (start, end) = process_range(range).map_err(|make_error| make_error("shuffle", head))?;
+    let len = vals.len();
+    let start = start.clamp(0, len as isize) as usize;
+    let end = end.clamp(-1, len as isize - 1);
+    let end = if end < 0 { 0 } else { end as usize + 1 };
+
+    if start < end {
+        vals[start..end.min(len)].shuffle(rng);
+    }
+
+    Ok(())
+}
+
+/// Shuffle a record, honoring `--values-only`: by default, whole key-value pairs are
+/// shuffled together (so the keys' display order can move too), while `values_only`
+/// keeps the keys in their original order and only permutes the values among them.
+fn shuffle_record(
+    record: Record,
+    values_only: bool,
+    range: Option<&Range>,
+    head: Span,
+    rng: &mut dyn RngCore,
+) -> Result<Record, ShellError> {
+    if values_only {
+        let mut vals = record.vals;
+        shuffle_slice(&mut vals, range, head, rng)?;
+        return Ok(Record {
+            cols: record.cols,
+            vals,
+        });
+    }
+
+    let mut pairs: Vec<(String, Value)> = record.cols.into_iter().zip(record.vals).collect();
+    shuffle_slice(&mut pairs, range, head, rng)?;
+    let (cols, vals) = pairs.into_iter().unzip();
+    Ok(Record { cols, vals })
+}
+
+/// Group `vals` by `column`'s value, shuffle the rows within each group, then
+/// concatenate the groups back in their original first-appearance order.
+fn shuffle_stable_groups(
+    vals: Vec<Value>,
+    column: &Spanned<String>,
+    rng: &mut dyn RngCore,
+) -> Result<Vec<Value>, ShellError> {
+    let mut groups: IndexMap<String, Vec<Value>> = IndexMap::new();
+
+    for val in vals {
+        let src_span = val.span();
+        let key = val
+            .get_data_by_key(&column.item)
+            .ok_or_else(|| ShellError::CantFindColumn {
+                col_name: column.item.clone(),
+                span: column.span,
+                src_span,
+            })?
+            .as_string()?;
+        groups.entry(key).or_default().push(val);
+    }
+
+    let mut result = Vec::new();
+    for (_, mut group) in groups {
+        group.shuffle(rng);
+        result.extend(group);
+    }
+
+    Ok(result)
+}
+
+/// Split `vals` into consecutive chunks of `chunk_size`, shuffle the order of the chunks
+/// (the last chunk may be smaller if `vals.len()` isn't a multiple of `chunk_size`), then
+/// flatten back into a single `Vec`, preserving the original order within each chunk.
+fn shuffle_chunks(vals: Vec<Value>, chunk_size: usize, rng: &mut dyn RngCore) -> Vec<Value> {
+    let mut chunks: Vec<Vec<Value>> = vals
+        .into_iter()
+        .fold(Vec::new(), |mut chunks: Vec<Vec<Value>>, val| {
+            match chunks.last_mut() {
+                Some(chunk) if chunk.len() < chunk_size => chunk.push(val),
+                _ => chunks.push(vec![val]),
+            }
+            chunks
+        });
+
+    chunks.shuffle(rng);
+    chunks.into_iter().flatten().collect()
+}
+
+/// Order `vals` by the hash of each row's result from running `closure` once per row.
+///
+/// Rows are sorted by the hash of their closure output rather than shuffled with a source
+/// of real randomness, so the same input and the same closure always produce the same
+/// order. `DefaultHasher::new()` always starts from the same fixed internal keys (it only
+/// randomizes when seeded via `RandomState`, which this doesn't use), so hashing the same
+/// value twice, even across separate runs of `nu`, always yields the same hash -- no seed
+/// needed for reproducibility.
+fn shuffle_by_key(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    closure: Closure,
+    vals: Vec<Value>,
+    head: Span,
+) -> Result<Vec<Value>, ShellError> {
+    let block = engine_state.get_block(closure.block_id).clone();
+    let mut stack = stack.captures_to_stack(&closure.captures);
+    let orig_env_vars = stack.env_vars.clone();
+    let orig_env_hidden = stack.env_hidden.clone();
+    let config = engine_state.get_config().clone();
+
+    let mut keyed = Vec::with_capacity(vals.len());
+    for val in vals {
+        stack.with_env(&orig_env_vars, &orig_env_hidden);
+
+        if let Some(var) = block.signature.get_positional(0) {
+            if let Some(var_id) = &var.var_id {
+                stack.add_var(*var_id, val.clone());
+            }
+        }
+
+        let output = eval_block(
+            engine_state,
+            &mut stack,
+            &block,
+            val.clone().into_pipeline_data(),
+            false,
+            false,
+        )?
+        .into_value(head);
+
+        let mut hasher = DefaultHasher::new();
+        output.into_string(", ", &config).hash(&mut hasher);
+        keyed.push((hasher.finish(), val));
+    }
+
+    keyed.sort_by_key(|(key, _)| *key);
+    Ok(keyed.into_iter().map(|(_, val)| val).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nu_protocol::{RangeInclusion, Span};
+
+    /// Builds a `{id, category}` record so tests can assert on both the category
+    /// block order and which ids ended up in which block.
+    fn categorized_row(id: i64, category: &str, span: Span) -> Value {
+        Value::record(
+            Record {
+                cols: vec!["id".to_string(), "category".to_string()],
+                vals: vec![Value::int(id, span), Value::string(category, span)],
+            },
+            span,
+        )
+    }
+
+    #[test]
+    fn stable_groups_by_keeps_category_blocks_in_first_appearance_order() {
+        let span = Span::test_data();
+        let vals = vec![
+            categorized_row(1, "a", span),
+            categorized_row(2, "b", span),
+            categorized_row(3, "a", span),
+            categorized_row(4, "b", span),
+            categorized_row(5, "a", span),
+        ];
+        let column = Spanned {
+            item: "category".to_string(),
+            span,
+        };
+
+        let shuffled = shuffle_stable_groups(vals, &column, &mut thread_rng())
+            .expect("category column exists");
+
+        // Regardless of how the random shuffle landed within each group, the "a" block
+        // (ids 1, 3, 5) must precede the "b" block (ids 2, 4), since "a" appeared first.
+        let categories: Vec<String> = shuffled
+            .iter()
+            .map(|v| v.get_data_by_key("category").unwrap().as_string().unwrap())
+            .collect();
+        assert_eq!(categories, vec!["a", "a", "a", "b", "b"]);
+
+        let mut a_ids: Vec<i64> = shuffled[0..3]
+            .iter()
+            .map(|v| v.get_data_by_key("id").unwrap().as_int().unwrap())
+            .collect();
+        a_ids.sort_unstable();
+        assert_eq!(a_ids, vec![1, 3, 5]);
+
+        let mut b_ids: Vec<i64> = shuffled[3..5]
+            .iter()
+            .map(|v| v.get_data_by_key("id").unwrap().as_int().unwrap())
+            .collect();
+        b_ids.sort_unstable();
+        assert_eq!(b_ids, vec![2, 4]);
+    }
+
+    #[test]
+    fn shuffle_record_with_values_only_keeps_key_order_fixed() {
+        let span = Span::test_data();
+        let record = Record {
+            cols: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            vals: vec![Value::int(1, span), Value::int(2, span), Value::int(3, span)],
+        };
+
+        let shuffled =
+            shuffle_record(record.clone(), true, None, span, make_rng(Some(42)).as_mut())
+                .expect("shuffle succeeds");
+
+        assert_eq!(shuffled.cols, record.cols);
+        // The values moved: some key now maps to a different value than before.
+        let unchanged = shuffled
+            .vals
+            .iter()
+            .zip(&record.vals)
+            .all(|(shuffled, original)| shuffled == original);
+        assert!(!unchanged);
+    }
+
+    #[test]
+    fn shuffle_record_without_values_only_moves_keys_and_values_together() {
+        let span = Span::test_data();
+        let record = Record {
+            cols: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            vals: vec![Value::int(1, span), Value::int(2, span), Value::int(3, span)],
+        };
+
+        let shuffled =
+            shuffle_record(record.clone(), false, None, span, make_rng(Some(42)).as_mut())
+                .expect("shuffle succeeds");
+
+        // Whichever order the pairs landed in, each key is still paired with its own
+        // original value -- only their position in the record may have moved.
+        for (col, val) in shuffled.cols.iter().zip(&shuffled.vals) {
+            let original_index = record.cols.iter().position(|c| c == col).unwrap();
+            assert_eq!(*val, record.vals[original_index]);
+        }
+
+        let mut sorted_cols = shuffled.cols.clone();
+        sorted_cols.sort();
+        assert_eq!(sorted_cols, record.cols);
+    }
+
+    #[test]
+    fn shuffle_slice_leaves_elements_outside_the_range_untouched() {
+        let span = Span::test_data();
+        let mut vals: Vec<Value> = (0..10).map(|i| Value::int(i, span)).collect();
+
+        let range = Range {
+            from: Value::int(2, span),
+            incr: Value::int(1, span),
+            to: Value::int(4, span),
+            inclusion: RangeInclusion::Inclusive,
+        };
+
+        shuffle_slice(&mut vals, Some(&range), span, &mut thread_rng()).expect("range is valid");
+
+        let before: Vec<i64> = (0..2).collect();
+        let after: Vec<i64> = (5..10).collect();
+        let actual_before: Vec<i64> = vals[0..2].iter().map(|v| v.as_int().unwrap()).collect();
+        let actual_after: Vec<i64> = vals[5..10].iter().map(|v| v.as_int().unwrap()).collect();
+
+        assert_eq!(before, actual_before);
+        assert_eq!(after, actual_after);
+    }
+
+    #[test]
+    fn shuffle_chunks_preserves_order_within_each_chunk() {
+        let span = Span::test_data();
+        let vals: Vec<Value> = (0..9).map(|i| Value::int(i, span)).collect();
+
+        let shuffled = shuffle_chunks(vals, 3, &mut thread_rng());
+
+        let mut chunk_starts: Vec<i64> = shuffled
+            .chunks(3)
+            .map(|chunk| chunk[0].as_int().unwrap())
+            .collect();
+        for chunk in shuffled.chunks(3) {
+            let ids: Vec<i64> = chunk.iter().map(|v| v.as_int().unwrap()).collect();
+            // Each original chunk was `[n, n+1, n+2]`; whichever chunk this is, its
+            // elements must still be in that ascending, contiguous order.
+            assert_eq!(ids, vec![ids[0], ids[0] + 1, ids[0] + 2]);
+        }
+        chunk_starts.sort_unstable();
+        assert_eq!(chunk_starts, vec![0, 3, 6]);
+    }
+
+    #[test]
+    fn make_rng_with_the_same_seed_produces_the_same_shuffle() {
+        let span = Span::test_data();
+        let vals: Vec<Value> = (0..20).map(|i| Value::int(i, span)).collect();
+
+        let first = shuffle_chunks(vals.clone(), 1, make_rng(Some(42)).as_mut());
+        let second = shuffle_chunks(vals, 1, make_rng(Some(42)).as_mut());
+
+        let first_ids: Vec<i64> = first.iter().map(|v| v.as_int().unwrap()).collect();
+        let second_ids: Vec<i64> = second.iter().map(|v| v.as_int().unwrap()).collect();
+        assert_eq!(first_ids, second_ids);
+    }
+
+    #[test]
+    fn the_same_rng_key_produces_the_same_shuffle() {
+        let span = Span::test_data();
+        let vals: Vec<Value> = (0..20).map(|i| Value::int(i, span)).collect();
+
+        let seed = derive_seed_from_key("players");
+        let first = shuffle_chunks(vals.clone(), 1, make_rng(Some(seed)).as_mut());
+        let second = shuffle_chunks(vals, 1, make_rng(Some(seed)).as_mut());
+
+        let first_ids: Vec<i64> = first.iter().map(|v| v.as_int().unwrap()).collect();
+        let second_ids: Vec<i64> = second.iter().map(|v| v.as_int().unwrap()).collect();
+        assert_eq!(first_ids, second_ids);
+    }
+
+    #[test]
+    fn different_rng_keys_derive_different_seeds() {
+        assert_ne!(
+            derive_seed_from_key("players"),
+            derive_seed_from_key("cards")
+        );
+    }
+
+    #[test]
+    fn finish_list_into_record_reports_count_and_keeps_all_items() {
+        let span = Span::test_data();
+        let vals: Vec<Value> = (0..5).map(|i| Value::int(i, span)).collect();
+
+        let output = finish_list(vals.clone(), true, span, None).into_value(span);
+
+        let Value::Record { val: record, .. } = output else {
+            panic!("expected a record");
+        };
+        assert_eq!(record.cols, vec!["count".to_string(), "items".to_string()]);
+        assert_eq!(record.vals[0], Value::int(5, span));
+        let Value::List { vals: items, .. } = &record.vals[1] else {
+            panic!("expected a list for items");
+        };
+        let mut ids: Vec<i64> = items.iter().map(|v| v.as_int().unwrap()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn finish_list_without_into_record_returns_the_bare_list() {
+        let span = Span::test_data();
+        let vals: Vec<Value> = (0..3).map(|i| Value::int(i, span)).collect();
+
+        let output = finish_list(vals, false, span, None).into_value(span);
+
+        assert!(matches!(output, Value::List { .. }));
+    }
+
+    #[test]
+    fn hashing_the_same_value_twice_produces_the_same_hash() {
+        // Stand-in for shuffle_by_key's per-row hashing: confirms DefaultHasher::new()'s
+        // fixed keys make the hash of a given string reproducible within and across runs,
+        // which is what lets `shuffle --by` avoid needing a seed.
+        let hash_of = |s: &str| {
+            let mut hasher = DefaultHasher::new();
+            s.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_eq!(hash_of("alice"), hash_of("alice"));
+        assert_ne!(hash_of("alice"), hash_of("bob"));
     }
 }
@@ -34,6 +34,22 @@ impl Command for Upsert {
                 SyntaxShape::Any,
                 "the new value to give the cell(s), or a closure to create the value",
             )
+            .switch(
+                "if-not-exists",
+                "only insert if the cell path doesn't already have a value; never overwrite",
+                None,
+            )
+            .switch(
+                "create-path",
+                "create missing intermediate records on the way to the cell path instead of erroring; an intermediate that exists but isn't a record still errors",
+                None,
+            )
+            .named(
+                "default",
+                SyntaxShape::Any,
+                "when the replacement is a closure and it yields nothing (e.g. a `match` with no matching arm), use this value instead; an explicit null returned by the closure is left as null",
+                None,
+            )
             .allow_variants_without_examples(true)
             .category(Category::Filters)
     }
@@ -42,6 +58,10 @@ impl Command for Upsert {
         "Update an existing column to have a new value, or insert a new column."
     }
 
+    fn extra_usage(&self) -> &str {
+        "A `*` path member applies the rest of the cell path to every element of a list-valued cell, e.g. `items.*.price` updates `price` on every record in the `items` list. --default only applies to the closure form, and only when the closure yields nothing at all (e.g. a `match` with no matching arm) -- an explicit null returned by the closure is a deliberate value and is left as null. A negative index (e.g. `(-1)` for the last element) counts from the end of a list; resolving it requires knowing the list's length up front, so a streamed input is collected into memory before the index is resolved."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["add"]
     }
@@ -108,6 +128,20 @@ impl Command for Upsert {
                 Span::test_data(),
             )),
         },
+        Example {
+            description: "Create missing intermediate records on the way to a deep cell path",
+            example: "{} | upsert a.b.c 1 --create-path",
+            result: Some(Value::test_record(Record {
+                cols: vec!["a".into()],
+                vals: vec![Value::test_record(Record {
+                    cols: vec!["b".into()],
+                    vals: vec![Value::test_record(Record {
+                        cols: vec!["c".into()],
+                        vals: vec![Value::test_int(1)],
+                    })],
+                })],
+            })),
+        },
         Example {
             description: "Upsert an int into a list, inserting a new value based on the index",
             example: "[1 2 3] | upsert 3 4",
@@ -121,6 +155,50 @@ impl Command for Upsert {
                 Span::test_data(),
             )),
         },
+        Example {
+            description: "Upsert an int into a list, using a negative index to count from the end",
+            example: "[1 2 3] | upsert (-1) 9",
+            result: Some(Value::list(
+                vec![Value::test_int(1), Value::test_int(2), Value::test_int(9)],
+                Span::test_data(),
+            )),
+        },
+        Example {
+            description: "Insert a value only if the column doesn't already have one",
+            example: "{'name': 'nu', 'stars': 5} | upsert stars 0 --if-not-exists",
+            result: Some(Value::test_record(Record {
+                cols: vec!["name".into(), "stars".into()],
+                vals: vec![Value::test_string("nu"), Value::test_int(5)],
+            })),
+        },
+        Example {
+            description: "Fall back to a default when the closure yields nothing at all, e.g. a match with no matching arm",
+            example: "{'kind': 'other'} | upsert tags {|e| match $e.kind { 'fruit' => ['food'] } } --default []",
+            result: Some(Value::test_record(Record {
+                cols: vec!["kind".into(), "tags".into()],
+                vals: vec![Value::test_string("other"), Value::test_list(vec![])],
+            })),
+        },
+        Example {
+            description: "Use a wildcard cell path member to set a field on every element of a list-valued cell",
+            example: "{'items': [{'price': 1}, {'price': 2}]} | upsert items.*.on-sale true",
+            result: Some(Value::test_record(Record {
+                cols: vec!["items".into()],
+                vals: vec![Value::list(
+                    vec![
+                        Value::test_record(Record {
+                            cols: vec!["price".into(), "on-sale".into()],
+                            vals: vec![Value::test_int(1), Value::test_bool(true)],
+                        }),
+                        Value::test_record(Record {
+                            cols: vec!["price".into(), "on-sale".into()],
+                            vals: vec![Value::test_int(2), Value::test_bool(true)],
+                        }),
+                    ],
+                    Span::test_data(),
+                )],
+            })),
+        },
         ]
     }
 }
@@ -133,8 +211,11 @@ fn upsert(
 ) -> Result<PipelineData, ShellError> {
     let span = call.head;
 
-    let cell_path: CellPath = call.req(engine_state, stack, 0)?;
+    let field: Value = call.req(engine_state, stack, 0)?;
     let replacement: Value = call.req(engine_state, stack, 1)?;
+    let if_not_exists = call.has_flag("if-not-exists");
+    let create_path = call.has_flag("create-path");
+    let default: Option<Value> = call.get_flag(engine_state, stack, "default")?;
 
     let redirect_stdout = call.redirect_stdout;
     let redirect_stderr = call.redirect_stderr;
@@ -142,6 +223,8 @@ fn upsert(
     let engine_state = engine_state.clone();
     let ctrlc = engine_state.ctrlc.clone();
 
+    let (cell_path, input) = resolve_negative_index(field, input, ctrlc.clone())?;
+
     // Replace is a block, so set it up and run it instead of using it as the replacement
     if replacement.as_block().is_ok() {
         let capture_block: Closure = FromValue::from_value(&replacement)?;
@@ -153,6 +236,11 @@ fn upsert(
 
         input.map(
             move |mut input| {
+                let wildcard = has_wildcard_member(&cell_path.members);
+                if if_not_exists && !wildcard && already_has_value(&input, &cell_path.members) {
+                    return input;
+                }
+
                 // with_env() is used here to ensure that each iteration uses
                 // a different set of environment variables.
                 // Hence, a 'cd' in the first loop won't affect the next loop.
@@ -175,9 +263,24 @@ fn upsert(
 
                 match output {
                     Ok(pd) => {
-                        if let Err(e) =
-                            input.upsert_data_at_cell_path(&cell_path.members, pd.into_value(span))
-                        {
+                        let value = match (&pd, &default) {
+                            (PipelineData::Empty, Some(default)) => default.clone(),
+                            _ => pd.into_value(span),
+                        };
+
+                        let upsert_result = if if_not_exists {
+                            upsert_if_not_exists_at_cell_path(
+                                &mut input,
+                                &cell_path.members,
+                                value,
+                                create_path,
+                            )
+                        } else if create_path {
+                            input.upsert_data_at_cell_path_create_path(&cell_path.members, value)
+                        } else {
+                            input.upsert_data_at_cell_path(&cell_path.members, value)
+                        };
+                        if let Err(e) = upsert_result {
                             return Value::error(e, span);
                         }
 
@@ -189,6 +292,20 @@ fn upsert(
             ctrlc,
         )
     } else {
+        if let Some(PathMember::String { val, span, .. }) = cell_path.members.get(0) {
+            if matches!(
+                input,
+                PipelineData::Value(Value::List { .. }, ..) | PipelineData::ListStream(..)
+            ) {
+                return Err(ShellError::TypeMismatch {
+                    err_message: format!(
+                        "list upserts need an integer index, but found string '{val}'"
+                    ),
+                    span: *span,
+                });
+            }
+        }
+
         if let Some(PathMember::Int { val, span, .. }) = cell_path.members.get(0) {
             let mut input = input.into_iter();
             let mut pre_elems = vec![];
@@ -205,7 +322,17 @@ fn upsert(
             }
 
             // Skip over the replaced value
-            let _ = input.next();
+            let existing = input.next();
+            let keep_existing = if_not_exists
+                && matches!(&existing, Some(v) if !matches!(v, Value::Nothing { .. }));
+
+            if keep_existing {
+                return Ok(pre_elems
+                    .into_iter()
+                    .chain(existing)
+                    .chain(input)
+                    .into_pipeline_data(ctrlc));
+            }
 
             return Ok(pre_elems
                 .into_iter()
@@ -216,9 +343,26 @@ fn upsert(
 
         input.map(
             move |mut input| {
+                let wildcard = has_wildcard_member(&cell_path.members);
+                if if_not_exists && !wildcard && already_has_value(&input, &cell_path.members) {
+                    return input;
+                }
+
                 let replacement = replacement.clone();
 
-                if let Err(e) = input.upsert_data_at_cell_path(&cell_path.members, replacement) {
+                let upsert_result = if if_not_exists {
+                    upsert_if_not_exists_at_cell_path(
+                        &mut input,
+                        &cell_path.members,
+                        replacement,
+                        create_path,
+                    )
+                } else if create_path {
+                    input.upsert_data_at_cell_path_create_path(&cell_path.members, replacement)
+                } else {
+                    input.upsert_data_at_cell_path(&cell_path.members, replacement)
+                };
+                if let Err(e) = upsert_result {
                     return Value::error(e, span);
                 }
 
@@ -229,6 +373,135 @@ fn upsert(
     }
 }
 
+/// If `field` is a bare negative integer (e.g. `(-1)`), resolve it to a non-negative index
+/// counting from the end of `input`, e.g. `-1` is the last element. This requires materializing
+/// `input` into a `Vec` up front, since a negative index needs to know the total length before
+/// it can be resolved -- something a stream can't answer without first being collected. Any
+/// other `field` (a string, an already-built cell path, a non-negative int) is converted to a
+/// [`CellPath`] the usual way via [`FromValue`], and `input` is returned untouched (still a
+/// stream, if it was one).
+fn resolve_negative_index(
+    field: Value,
+    input: PipelineData,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<(CellPath, PipelineData), ShellError> {
+    let Value::Int { val, span } = field else {
+        return Ok((FromValue::from_value(&field)?, input));
+    };
+
+    if val >= 0 {
+        return Ok((FromValue::from_value(&field)?, input));
+    }
+
+    let elems: Vec<Value> = input.into_iter().collect();
+    let resolved = elems.len() as i64 + val;
+    if resolved < 0 {
+        return Err(ShellError::GenericError(
+            format!(
+                "index {val} is out of range for a list of {} element(s)",
+                elems.len()
+            ),
+            "index too small".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    let cell_path = CellPath {
+        members: vec![PathMember::Int {
+            val: resolved as usize,
+            span,
+            optional: false,
+        }],
+    };
+
+    Ok((cell_path, elems.into_iter().into_pipeline_data(ctrlc)))
+}
+
+/// Whether `value` already has a non-null value at `cell_path`, i.e. `--if-not-exists` should
+/// leave it alone rather than overwrite it.
+///
+/// `cell_path` must not contain a wildcard (`"*"`) member: [`Value::follow_cell_path`] has no
+/// wildcard semantics, unlike [`Value::upsert_data_at_cell_path`]. Callers that may have a
+/// wildcard member should use [`upsert_if_not_exists_at_cell_path`] instead, which recurses
+/// around this limitation the same way the plain upsert path does.
+fn already_has_value(value: &Value, cell_path: &[PathMember]) -> bool {
+    matches!(
+        value.clone().follow_cell_path(cell_path, false),
+        Ok(v) if !matches!(v, Value::Nothing { .. })
+    )
+}
+
+/// Whether any member of `cell_path` is the wildcard (`"*"`) member.
+fn has_wildcard_member(cell_path: &[PathMember]) -> bool {
+    cell_path
+        .iter()
+        .any(|member| matches!(member, PathMember::String { val, .. } if val == "*"))
+}
+
+/// Upsert `new_val` at `cell_path`, honoring `--if-not-exists` for each element a wildcard
+/// member matches individually, instead of gating the whole path on a single check the way
+/// `already_has_value` does. For example, with `items.*.price`, rows that already have a
+/// `price` are left alone while rows that don't get `new_val` -- mirroring the per-element
+/// recursion [`Value::upsert_data_at_cell_path`] itself does for wildcard members. `create_path`
+/// mirrors `--create-path`, selecting between [`Value::upsert_data_at_cell_path`] and
+/// [`Value::upsert_data_at_cell_path_create_path`] for the actual write.
+fn upsert_if_not_exists_at_cell_path(
+    value: &mut Value,
+    cell_path: &[PathMember],
+    new_val: Value,
+    create_path: bool,
+) -> Result<(), ShellError> {
+    if let Some(PathMember::String { val: col_name, span, .. }) = cell_path.first() {
+        if col_name == "*" {
+            return match value {
+                Value::List { vals, .. } => {
+                    for val in vals.iter_mut() {
+                        upsert_if_not_exists_at_cell_path(
+                            val,
+                            &cell_path[1..],
+                            new_val.clone(),
+                            create_path,
+                        )?;
+                    }
+                    Ok(())
+                }
+                Value::Error { error, .. } => Err(*error.to_owned()),
+                v => Err(ShellError::NotAList {
+                    dst_span: *span,
+                    src_span: v.span(),
+                }),
+            };
+        }
+    }
+
+    if let Some((first, rest)) = cell_path.split_first() {
+        if has_wildcard_member(rest) {
+            if let (PathMember::String { .. }, Value::List { vals, .. }) = (first, &mut *value) {
+                for val in vals.iter_mut() {
+                    upsert_if_not_exists_at_cell_path(val, cell_path, new_val.clone(), create_path)?;
+                }
+                return Ok(());
+            }
+            let first = std::slice::from_ref(first);
+            let mut inner = value.clone().follow_cell_path(first, false)?;
+            upsert_if_not_exists_at_cell_path(&mut inner, rest, new_val, create_path)?;
+            return value.upsert_data_at_cell_path(first, inner);
+        }
+    }
+
+    if already_has_value(value, cell_path) {
+        return Ok(());
+    }
+
+    if create_path {
+        value.upsert_data_at_cell_path_create_path(cell_path, new_val)
+    } else {
+        value.upsert_data_at_cell_path(cell_path, new_val)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,11 +1,17 @@
-use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::{atomic::AtomicBool, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
 
+use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
     Category, Example, IntoInterruptiblePipelineData, PipelineData, Record, ShellError, Signature,
-    Type, Value,
+    Span, Spanned, SyntaxShape, Type, Value,
 };
+use nu_system::ProcessInfo;
+use sysinfo::{System, SystemExt};
 
 #[derive(Clone)]
 pub struct Ps;
@@ -23,6 +29,50 @@ impl Command for Ps {
                 "list all available columns for each entry",
                 Some('l'),
             )
+            .switch(
+                "tree",
+                "nest child processes under their parent in a 'children' column, only listing root processes at the top level",
+                Some('t'),
+            )
+            .switch(
+                "self",
+                "only return the current nu process's row, the same one `ps | where pid == $nu.pid` would find",
+                None,
+            )
+            .named(
+                "pid-source",
+                SyntaxShape::String,
+                "which pid --self resolves to: \"self\" (default) for this nu process's own pid, or \"parent\" for whatever spawned it -- useful when nu is invoked as a subprocess (e.g. embedded in another program) and \"the current process\" should mean the embedding process. Only has an effect together with --self",
+                None,
+            )
+            .switch(
+                "mem-percent",
+                "add a mem_percent column: each process's resident memory as a percentage (0-100) of total system RAM",
+                None,
+            )
+            .switch(
+                "user-only",
+                "filter out kernel/system threads: on Linux, keep only processes owned by a real user account (uid >= 1000) or with a non-empty command line; a no-op on other platforms",
+                None,
+            )
+            .named(
+                "sort-by",
+                SyntaxShape::String,
+                "sort internally (descending) by this column before output, avoiding building rows for processes --top will discard; one of: pid, ppid, name, cpu, mem, virtual",
+                None,
+            )
+            .named(
+                "top",
+                SyntaxShape::Int,
+                "keep only this many rows after sorting",
+                None,
+            )
+            .named(
+                "refresh",
+                SyntaxShape::Duration,
+                "instead of a single table, emit a fresh table every interval as a stream, until interrupted; never terminates on its own",
+                None,
+            )
             .filter()
             .category(Category::System)
     }
@@ -31,6 +81,10 @@ impl Command for Ps {
         "View information about system processes."
     }
 
+    fn extra_usage(&self) -> &str {
+        "--self is sugar for `ps | where pid == $nu.pid`, for the common case of wanting only the current nu process's row. --pid-source changes what \"current\" means for --self: \"self\" (the default) is nu's own pid, while \"parent\" is the pid of whatever spawned this nu process -- the distinction matters when nu is invoked as a subprocess of something else (e.g. embedded in another program), where `$nu.pid` and the caller's idea of \"the current process\" diverge. --mem-percent queries total system RAM once per invocation, not once per row. --user-only's uid >= 1000 or non-empty command line heuristic only runs on Linux, where /proc exposes a process's owning uid and cmdline; it's the conventional first uid assigned to a real user account on most distributions, below which uids belong to the system or service accounts, and kernel threads additionally have no cmdline to fall back on. --refresh re-samples on a timer, emitting one table per tick, for a lightweight `top`-like loop (e.g. piped into `explore`); it never stops by itself, so interrupt with ctrl-c to end it. --long also adds a parent column: the parent process's name, resolved from `ppid` against this same listing (not a fresh self-join), or null if the parent isn't part of it."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["procedures", "operations", "tasks", "ops"]
     }
@@ -38,11 +92,11 @@ impl Command for Ps {
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        run_ps(engine_state, call)
+        run_ps(engine_state, stack, call)
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -72,34 +126,319 @@ impl Command for Ps {
                 example: "ps | where pid == $nu.pid | get ppid",
                 result: None,
             },
+            Example {
+                description: "Get just the current nu process's row",
+                example: "ps --self",
+                result: None,
+            },
+            Example {
+                description: "When nu is invoked as a subprocess, get the row of whatever spawned it instead of nu's own row",
+                example: "ps --self --pid-source parent",
+                result: None,
+            },
+            Example {
+                description: "List the system processes as a tree of parents and children",
+                example: "ps --tree",
+                result: None,
+            },
+            Example {
+                description: "List the top 5 processes by disk write rate",
+                example: "ps --long | sort-by disk_write | last 5",
+                result: None,
+            },
+            Example {
+                description: "Show each process's memory usage as a percentage of total RAM, top-style",
+                example: "ps --mem-percent | sort-by mem | last 5",
+                result: None,
+            },
+            Example {
+                description: "Sort and truncate at the source instead of sorting the whole table",
+                example: "ps --sort-by mem --top 5",
+                result: None,
+            },
+            Example {
+                description: "List each process next to its parent's name, without a self-join",
+                example: "ps --long | select name parent",
+                result: None,
+            },
+            Example {
+                description: "Get the individual argv entries of the current nu process",
+                example: "ps --long | where pid == $nu.pid | get command_args.0",
+                result: None,
+            },
+            Example {
+                description: "Find zombie processes portably across platforms",
+                example: "ps | where state == zombie",
+                result: None,
+            },
+            Example {
+                description: "Hide kernel/system threads, keeping only real user processes (Linux only; a no-op elsewhere)",
+                example: "ps --user-only",
+                result: None,
+            },
+            Example {
+                description: "Re-sample every second for a lightweight top-like view (runs until interrupted)",
+                example: "ps --refresh 1sec | explore",
+                result: None,
+            },
         ]
     }
 }
 
-fn run_ps(engine_state: &EngineState, call: &Call) -> Result<PipelineData, ShellError> {
-    let mut output = vec![];
+const SORT_COLUMNS: &[&str] = &["pid", "ppid", "name", "cpu", "mem", "virtual"];
+const PID_SOURCES: &[&str] = &["self", "parent"];
+
+/// Total installed system RAM in bytes, or `None` if this platform's backend couldn't report it.
+fn get_total_memory() -> Option<u64> {
+    let mut sys = System::new();
+    sys.refresh_memory();
+    let total = sys.total_memory();
+    (total > 0).then_some(total)
+}
+
+/// Collapse a platform's free-form `status()` string into one of a small, documented set
+/// so `ps | where state == zombie` is portable across platforms; the raw value is still
+/// available in the `status` column for anyone who wants the detail.
+#[cfg(not(windows))]
+fn normalize_status(raw: &str) -> &'static str {
+    match raw {
+        "Running" => "running",
+        "Sleeping" | "Sleep" | "Disk sleep" | "Uninterruptible" | "Waiting" => "sleeping",
+        "Stopped" | "Halted" => "stopped",
+        "Zombie" => "zombie",
+        _ => "unknown",
+    }
+}
+
+/// `--user-only`'s heuristic for "not a kernel/system thread": owned by a uid >= 1000 (the
+/// conventional first uid assigned to a real user account; below it is root and system/service
+/// accounts), or failing that, having a non-empty command line (kernel threads never have one).
+/// Pulled out of [`is_user_process`] and taking primitives rather than a [`ProcessInfo`] so it
+/// can be unit-tested without depending on which kernel threads happen to be visible in `/proc`.
+fn is_user_owned(uid: u32, command_args: &[String]) -> bool {
+    uid >= 1000 || !command_args.is_empty()
+}
+
+/// Only Linux's backend exposes a process's uid, so this is a no-op everywhere else.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn is_user_process(proc: &ProcessInfo) -> bool {
+    is_user_owned(proc.uid(), &proc.command_args())
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+fn is_user_process(_proc: &ProcessInfo) -> bool {
+    true
+}
+
+/// Every flag that shapes a single sample's output, gathered so a sample can be re-taken
+/// identically on each tick of `--refresh`.
+struct PsOptions {
+    long: bool,
+    tree: bool,
+    self_only: bool,
+    pid_source: String,
+    user_only: bool,
+    mem_percent: bool,
+    sort_by: Option<String>,
+    top: Option<usize>,
+}
+
+const REFRESH_CTRLC_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Sleep for `duration`, checking `ctrlc` every [`REFRESH_CTRLC_CHECK_INTERVAL`] instead of in
+/// one long blocking sleep, so `ps --refresh` with a long interval still stops promptly when
+/// interrupted partway through it.
+fn interruptible_sleep(duration: Duration, ctrlc: &Option<Arc<AtomicBool>>) {
+    let start = Instant::now();
+    while start.elapsed() < duration {
+        if nu_utils::ctrl_c::was_pressed(ctrlc) {
+            return;
+        }
+        thread::sleep(REFRESH_CTRLC_CHECK_INTERVAL.min(duration.saturating_sub(start.elapsed())));
+    }
+}
+
+fn run_ps(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
     let span = call.head;
     let long = call.has_flag("long");
+    let tree = call.has_flag("tree");
+    let self_only = call.has_flag("self");
+    let pid_source: Option<Spanned<String>> = call.get_flag(engine_state, stack, "pid-source")?;
+    let user_only = call.has_flag("user-only");
+    let mem_percent = long || call.has_flag("mem-percent");
+    let sort_by: Option<Spanned<String>> = call.get_flag(engine_state, stack, "sort-by")?;
+    let top: Option<usize> = call.get_flag(engine_state, stack, "top")?;
+    let refresh: Option<i64> = call.get_flag(engine_state, stack, "refresh")?;
+
+    if let Some(sort_by) = &sort_by {
+        if !SORT_COLUMNS.contains(&sort_by.item.as_str()) {
+            return Err(ShellError::IncorrectValue {
+                msg: format!("sort-by must be one of: {}", SORT_COLUMNS.join(", ")),
+                val_span: sort_by.span,
+                call_span: span,
+            });
+        }
+    }
+
+    if let Some(pid_source) = &pid_source {
+        if !PID_SOURCES.contains(&pid_source.item.as_str()) {
+            return Err(ShellError::IncorrectValue {
+                msg: format!("pid-source must be one of: {}", PID_SOURCES.join(", ")),
+                val_span: pid_source.span,
+                call_span: span,
+            });
+        }
+    }
+
+    let options = PsOptions {
+        long,
+        tree,
+        self_only,
+        pid_source: pid_source.map_or_else(|| "self".to_string(), |pid_source| pid_source.item),
+        user_only,
+        mem_percent,
+        sort_by: sort_by.map(|sort_by| sort_by.item),
+        top,
+    };
+
+    if let Some(refresh) = refresh {
+        let interval = Duration::from_nanos(if refresh < 0 { 0 } else { refresh as u64 });
+        let ctrlc = engine_state.ctrlc.clone();
+        let loop_ctrlc = ctrlc.clone();
+        let mut first_tick = true;
+        let stream = std::iter::from_fn(move || {
+            if nu_utils::ctrl_c::was_pressed(&loop_ctrlc) {
+                return None;
+            }
+
+            if first_tick {
+                first_tick = false;
+            } else {
+                interruptible_sleep(interval, &loop_ctrlc);
+                if nu_utils::ctrl_c::was_pressed(&loop_ctrlc) {
+                    return None;
+                }
+            }
 
-    for proc in nu_system::collect_proc(Duration::from_millis(100), false) {
+            Some(Value::list(sample_processes(&options, span), span))
+        });
+
+        return Ok(stream.into_pipeline_data(ctrlc));
+    }
+
+    Ok(sample_processes(&options, span)
+        .into_iter()
+        .into_pipeline_data(engine_state.ctrlc.clone()))
+}
+
+/// Which pid `--self` resolves to, looked up against this same `procs` snapshot: nu's own pid
+/// (`"self"`, the default), or the pid of whatever spawned it (`"parent"`) -- useful when nu is
+/// invoked as a subprocess and the caller's idea of "the current process" is the embedding
+/// process, not nu itself. Falls back to nu's own pid if it isn't present in `procs` (it always
+/// should be); the parent's pid is returned whether or not the parent itself is in `procs`.
+fn resolve_target_pid(procs: &[ProcessInfo], pid_source: &str) -> i32 {
+    let my_pid = std::process::id() as i32;
+
+    if pid_source != "parent" {
+        return my_pid;
+    }
+
+    procs
+        .iter()
+        .find(|proc| proc.pid() == my_pid)
+        .map(|proc| proc.ppid())
+        .unwrap_or(my_pid)
+}
+
+/// Take a single sample of the system's processes, shaped by `options`: filtered, sorted,
+/// truncated, and either flattened to one row per process or nested into a `--tree`.
+fn sample_processes(options: &PsOptions, span: Span) -> Vec<Value> {
+    let total_memory = options.mem_percent.then(get_total_memory);
+
+    let mut procs = nu_system::collect_proc(Duration::from_millis(100), false);
+
+    if options.self_only {
+        let target_pid = resolve_target_pid(&procs, &options.pid_source);
+        procs.retain(|proc| proc.pid() == target_pid);
+    }
+
+    if options.user_only {
+        procs.retain(is_user_process);
+    }
+
+    if let Some(sort_by) = &options.sort_by {
+        procs.sort_by(|a, b| compare_procs(b, a, sort_by));
+    }
+    if let Some(top) = options.top {
+        procs.truncate(top);
+    }
+
+    // Resolved from this same snapshot, before any (pid, ppid) pair below is consumed, so
+    // `parent` only ever reports a process that's actually part of this listing.
+    let names_by_pid: HashMap<i64, String> = procs
+        .iter()
+        .map(|proc| (proc.pid() as i64, proc.name()))
+        .collect();
+
+    let mut output = vec![];
+    for proc in procs {
         let mut record = Record::new();
 
-        record.push("pid", Value::int(proc.pid() as i64, span));
-        record.push("ppid", Value::int(proc.ppid() as i64, span));
+        let pid = proc.pid() as i64;
+        let ppid = proc.ppid() as i64;
+
+        record.push("pid", Value::int(pid, span));
+        record.push("ppid", Value::int(ppid, span));
         record.push("name", Value::string(proc.name(), span));
 
         #[cfg(not(windows))]
         {
             // Hide status on Windows until we can find a good way to support it
-            record.push("status", Value::string(proc.status(), span));
+            let status = proc.status();
+            record.push("state", Value::string(normalize_status(&status), span));
+            record.push("status", Value::string(status, span));
         }
 
         record.push("cpu", Value::float(proc.cpu_usage(), span));
         record.push("mem", Value::filesize(proc.mem_size() as i64, span));
         record.push("virtual", Value::filesize(proc.virtual_size() as i64, span));
 
-        if long {
+        if options.mem_percent {
+            let percent = match total_memory.flatten() {
+                Some(total) if total > 0 => {
+                    Value::float(proc.mem_size() as f64 / total as f64 * 100.0, span)
+                }
+                _ => Value::nothing(span),
+            };
+            record.push("mem_percent", percent);
+        }
+
+        if options.long {
+            let parent = names_by_pid
+                .get(&ppid)
+                .map(|name| Value::string(name.clone(), span))
+                .unwrap_or_else(|| Value::nothing(span));
+            record.push("parent", parent);
+
+            let (disk_read, disk_write) = disk_usage_columns(proc.disk_usage(), span);
+            record.push("disk_read", disk_read);
+            record.push("disk_write", disk_write);
+
             record.push("command", Value::string(proc.command(), span));
+            record.push(
+                "command_args",
+                Value::list(
+                    proc.command_args()
+                        .into_iter()
+                        .map(|arg| Value::string(arg, span))
+                        .collect(),
+                    span,
+                ),
+            );
             #[cfg(windows)]
             {
                 record.push("cwd", Value::string(proc.cwd(), span));
@@ -116,10 +455,225 @@ fn run_ps(engine_state: &EngineState, call: &Call) -> Result<PipelineData, Shell
             }
         }
 
-        output.push(Value::record(record, span));
+        output.push((pid, ppid, record));
+    }
+
+    if options.tree {
+        build_process_tree(output, span)
+    } else {
+        output
+            .into_iter()
+            .map(|(_, _, record)| Value::record(record, span))
+            .collect()
     }
+}
+
+/// Compare two processes by `column`, one of [`SORT_COLUMNS`]. Assumes `column` has already
+/// been validated, so an unrecognized value falls back to treating the processes as equal.
+fn compare_procs(a: &ProcessInfo, b: &ProcessInfo, column: &str) -> std::cmp::Ordering {
+    match column {
+        "pid" => a.pid().cmp(&b.pid()),
+        "ppid" => a.ppid().cmp(&b.ppid()),
+        "name" => a.name().cmp(&b.name()),
+        "cpu" => a
+            .cpu_usage()
+            .partial_cmp(&b.cpu_usage())
+            .unwrap_or(std::cmp::Ordering::Equal),
+        "mem" => a.mem_size().cmp(&b.mem_size()),
+        "virtual" => a.virtual_size().cmp(&b.virtual_size()),
+        _ => std::cmp::Ordering::Equal,
+    }
+}
+
+/// Turn a process's `(read, write)` disk byte counters into `disk_read`/`disk_write` column
+/// values, reporting `null` for either side the platform backend couldn't provide.
+fn disk_usage_columns(disk_usage: Option<(u64, u64)>, span: Span) -> (Value, Value) {
+    match disk_usage {
+        Some((read, write)) => (
+            Value::filesize(read as i64, span),
+            Value::filesize(write as i64, span),
+        ),
+        None => (Value::nothing(span), Value::nothing(span)),
+    }
+}
+
+/// Nest each process's record under its parent's `children` column, returning only the
+/// root processes (those whose parent isn't present in `procs`) at the top level.
+fn build_process_tree(procs: Vec<(i64, i64, Record)>, span: Span) -> Vec<Value> {
+    let pids: std::collections::HashSet<i64> = procs.iter().map(|(pid, ..)| *pid).collect();
+
+    let mut children_by_ppid: HashMap<i64, Vec<i64>> = HashMap::new();
+    let mut records_by_pid: HashMap<i64, Record> = HashMap::new();
+    let mut roots = vec![];
 
-    Ok(output
+    for (pid, ppid, record) in procs {
+        if pids.contains(&ppid) {
+            children_by_ppid.entry(ppid).or_default().push(pid);
+        } else {
+            roots.push(pid);
+        }
+        records_by_pid.insert(pid, record);
+    }
+
+    fn attach_children(
+        pid: i64,
+        records_by_pid: &mut HashMap<i64, Record>,
+        children_by_ppid: &HashMap<i64, Vec<i64>>,
+        span: Span,
+    ) -> Value {
+        let mut record = records_by_pid
+            .remove(&pid)
+            .expect("pid was just inserted above");
+
+        let children = children_by_ppid
+            .get(&pid)
+            .map(|child_pids| {
+                child_pids
+                    .iter()
+                    .map(|&child_pid| {
+                        attach_children(child_pid, records_by_pid, children_by_ppid, span)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        record.push("children", Value::list(children, span));
+
+        Value::record(record, span)
+    }
+
+    roots
         .into_iter()
-        .into_pipeline_data(engine_state.ctrlc.clone()))
+        .map(|pid| attach_children(pid, &mut records_by_pid, &children_by_ppid, span))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn get_column<'a>(record: &'a Record, col: &str) -> Option<&'a Value> {
+        record
+            .cols
+            .iter()
+            .position(|c| c == col)
+            .map(|i| &record.vals[i])
+    }
+
+    fn row_for_pid(rows: &[Value], pid: i64) -> Option<Record> {
+        rows.iter().find_map(|row| {
+            let Value::Record { val, .. } = row else {
+                return None;
+            };
+            match get_column(val, "pid") {
+                Some(Value::Int { val: row_pid, .. }) if *row_pid == pid => Some(val.clone()),
+                _ => None,
+            }
+        })
+    }
+
+    #[test]
+    fn parent_column_resolves_to_the_real_parent_process_name() {
+        use std::process::{Command, Stdio};
+
+        let mut child = if cfg!(windows) {
+            Command::new("ping")
+                .args(["-n", "2", "127.0.0.1"])
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+        } else {
+            Command::new("sleep")
+                .arg("1")
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+        }
+        .expect("failed to spawn child process");
+
+        let child_pid = child.id() as i64;
+        let my_pid = std::process::id() as i64;
+
+        let options = PsOptions {
+            long: true,
+            tree: false,
+            self_only: false,
+            pid_source: "self".to_string(),
+            user_only: false,
+            mem_percent: false,
+            sort_by: None,
+            top: None,
+        };
+        let span = Span::test_data();
+        let rows = sample_processes(&options, span);
+
+        let my_name = row_for_pid(&rows, my_pid)
+            .and_then(|record| get_column(&record, "name").cloned())
+            .expect("current process should be in the listing");
+        let child_parent = row_for_pid(&rows, child_pid)
+            .and_then(|record| get_column(&record, "parent").cloned());
+
+        child.kill().ok();
+        child.wait().ok();
+
+        match (my_name, child_parent) {
+            (Value::String { val: my_name, .. }, Some(Value::String { val: parent, .. })) => {
+                assert_eq!(parent, my_name);
+            }
+            (_, other) => panic!("expected child's parent column to be the current process's name, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn disk_usage_columns_are_filesize_typed_when_available() {
+        let span = Span::test_data();
+        let (read, write) = disk_usage_columns(Some((1024, 2048)), span);
+        assert!(matches!(read, Value::Filesize { val: 1024, .. }));
+        assert!(matches!(write, Value::Filesize { val: 2048, .. }));
+    }
+
+    #[test]
+    fn disk_usage_columns_are_null_when_unavailable() {
+        let span = Span::test_data();
+        let (read, write) = disk_usage_columns(None, span);
+        assert!(matches!(read, Value::Nothing { .. }));
+        assert!(matches!(write, Value::Nothing { .. }));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn normalize_status_maps_known_raw_statuses() {
+        assert_eq!(normalize_status("Running"), "running");
+        assert_eq!(normalize_status("Sleeping"), "sleeping");
+        assert_eq!(normalize_status("Sleep"), "sleeping");
+        assert_eq!(normalize_status("Disk sleep"), "sleeping");
+        assert_eq!(normalize_status("Uninterruptible"), "sleeping");
+        assert_eq!(normalize_status("Waiting"), "sleeping");
+        assert_eq!(normalize_status("Stopped"), "stopped");
+        assert_eq!(normalize_status("Halted"), "stopped");
+        assert_eq!(normalize_status("Zombie"), "zombie");
+        assert_eq!(normalize_status("Tracing"), "unknown");
+        assert_eq!(normalize_status("Dead"), "unknown");
+        assert_eq!(normalize_status("Wakekill"), "unknown");
+        assert_eq!(normalize_status("Waking"), "unknown");
+        assert_eq!(normalize_status("Parked"), "unknown");
+        assert_eq!(normalize_status("Unknown"), "unknown");
+        assert_eq!(normalize_status("?"), "unknown");
+    }
+
+    #[test]
+    fn is_user_owned_excludes_root_owned_processes_with_no_command_line() {
+        // A true kernel thread: owned by root, no argv (e.g. `/proc/<pid>/cmdline` is empty).
+        assert!(!is_user_owned(0, &[]));
+    }
+
+    #[test]
+    fn is_user_owned_includes_root_owned_processes_with_a_command_line() {
+        // A real process root happens to own, e.g. sshd or a system service.
+        assert!(is_user_owned(0, &["sshd".to_string()]));
+    }
+
+    #[test]
+    fn is_user_owned_includes_processes_owned_by_a_real_user_account() {
+        assert!(is_user_owned(1000, &[]));
+    }
 }
@@ -0,0 +1,53 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn replace_reads_find_and_replace_patterns_from_files() {
+    Playground::setup("bytes_replace_from_file_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("find.bin", "bar"),
+            FileWithContent("replace.bin", "baz"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                'foo bar' | bytes replace --from-file find.bin --to-file replace.bin
+            "#
+        ));
+
+        assert_eq!(actual.out, "foo baz");
+    });
+}
+
+#[test]
+fn replace_from_file_errors_on_missing_file() {
+    Playground::setup("bytes_replace_from_file_test_2", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                'foo bar' | bytes replace --from-file does-not-exist.bin 'baz'
+            "#
+        ));
+
+        assert!(actual.err.contains("could not read pattern file"));
+    });
+}
+
+#[test]
+fn replace_closure_reverses_each_match() {
+    let actual = nu!(
+        "0x[AA BB AA] | bytes replace --all --closure {|match| $match | bytes reverse} 0x[AA BB] | to json -r"
+    );
+
+    // 0x[AA BB] reversed is 0x[BB AA], leaving the trailing unmatched 0xAA in place.
+    assert_eq!(actual.out, "[187,170,170]");
+}
+
+#[test]
+fn replace_closure_conflicts_with_replace_argument() {
+    let actual = nu!("0x[AA] | bytes replace --closure {|match| $match} 0x[AA] 0x[BB]");
+
+    assert!(actual.err.contains("--closure"));
+}
@@ -0,0 +1,19 @@
+use nu_test_support::nu;
+
+#[test]
+fn filesize_into_decimal() {
+    let actual = nu!("1KiB | into decimal");
+    assert_eq!(actual.out, "1024");
+}
+
+#[test]
+fn duration_into_decimal_default_seconds() {
+    let actual = nu!("1min | into decimal");
+    assert_eq!(actual.out, "60");
+}
+
+#[test]
+fn duration_into_decimal_with_unit() {
+    let actual = nu!("1sec | into decimal --unit ms");
+    assert_eq!(actual.out, "1000");
+}
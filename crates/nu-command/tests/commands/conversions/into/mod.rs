@@ -1 +1,2 @@
+mod decimal;
 mod int;
@@ -40,6 +40,35 @@ fn copies_a_file_impl(progress: bool) {
     });
 }
 
+#[test]
+fn dry_run_does_not_copy_the_file() {
+    Playground::setup("cp_test_dry_run_1", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("sample.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "cp --dry-run sample.txt copy.txt | get destination.0"
+        );
+
+        assert!(actual.out.ends_with("copy.txt"));
+        assert!(!dirs.test().join("copy.txt").exists());
+    });
+}
+
+#[test]
+fn dry_run_does_not_create_destination_directory() {
+    Playground::setup("cp_test_dry_run_2", |dirs, sandbox| {
+        sandbox.mkdir("dir_a").with_files(vec![EmptyFile("dir_a/one.txt")]);
+
+        nu!(
+            cwd: dirs.test(),
+            "cp -r --dry-run dir_a dir_b"
+        );
+
+        assert!(!dirs.test().join("dir_b").exists());
+    });
+}
+
 #[test]
 fn copies_the_file_inside_directory_if_path_to_copy_is_directory() {
     copies_the_file_inside_directory_if_path_to_copy_is_directory_impl(false);
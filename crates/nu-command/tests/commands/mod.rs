@@ -4,6 +4,7 @@ mod any;
 mod append;
 mod assignment;
 mod break_;
+mod bytes;
 mod cal;
 mod cd;
 mod compact;
@@ -65,6 +66,7 @@ mod path;
 mod platform;
 mod prepend;
 mod print;
+mod ps;
 #[cfg(feature = "sqlite")]
 mod query;
 mod random;
@@ -86,6 +88,7 @@ mod select;
 mod semicolon;
 mod seq;
 mod seq_char;
+mod shuffle;
 mod skip;
 mod sort;
 mod sort_by;
@@ -0,0 +1,85 @@
+use nu_test_support::nu;
+
+#[test]
+fn sort_by_and_top_limit_the_row_count() {
+    let actual = nu!("ps --sort-by mem --top 3 | length");
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn sort_by_rejects_unknown_column() {
+    let actual = nu!("ps --sort-by not-a-real-column | length");
+
+    assert!(actual.err.contains("sort-by"));
+}
+
+#[test]
+fn long_command_args_is_non_empty_for_the_current_process() {
+    let actual = nu!("ps --long | where pid == $nu.pid | get command_args.0 | length");
+
+    assert!(actual.out.parse::<usize>().unwrap_or(0) > 0);
+}
+
+#[test]
+fn self_returns_only_the_current_process_row() {
+    let actual = nu!("ps --self | get pid.0");
+
+    assert_eq!(actual.out, nu!("$nu.pid").out);
+}
+
+#[test]
+fn self_returns_exactly_one_row() {
+    let actual = nu!("ps --self | length");
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn pid_source_parent_differs_from_pid_source_self() {
+    let self_pid = nu!("ps --self | get pid.0").out;
+    let parent_pid = nu!("ps --self --pid-source parent | get pid.0").out;
+
+    assert_ne!(self_pid, parent_pid);
+}
+
+#[test]
+fn pid_source_rejects_unknown_value() {
+    let actual = nu!("ps --self --pid-source grandparent");
+
+    assert!(actual.err.contains("pid-source"));
+}
+
+#[test]
+fn mem_percent_is_within_0_to_100_for_the_current_process() {
+    let actual = nu!(
+        "ps --mem-percent --self | get mem_percent.0 | $in >= 0 and $in <= 100"
+    );
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn refresh_emits_a_table_per_tick() {
+    // The first tick is emitted immediately (no sleep), so `first` returns right away.
+    let actual = nu!("ps --refresh 10sec --self | first | length");
+
+    assert_eq!(actual.out, "1");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn user_only_keeps_every_process_with_a_non_empty_command_line() {
+    // `ps` doesn't expose a uid column, so the uid half of `is_user_owned`'s heuristic can't
+    // be exercised from here -- it's covered directly by that function's unit tests in
+    // `system/ps.rs`. But the other half ("a non-empty command line is always kept") doesn't
+    // depend on which kernel threads this environment's `/proc` happens to expose, so assert
+    // on it directly instead of on the row count.
+    let actual = nu!(
+        "let full_with_args = (ps --long | filter {|p| ($p.command_args | length) > 0 } | get pid);
+         let user_only_pids = (ps --user-only --long | get pid);
+         $full_with_args | all {|pid| $pid in $user_only_pids }"
+    );
+
+    assert_eq!(actual.out, "true");
+}
@@ -104,6 +104,21 @@ mod columns {
         assert_eq!(actual.out, "origin-stars-commit_author");
     }
 
+    #[test]
+    fn can_roll_right_except_a_pinned_column() {
+        let actual = nu!(pipeline(
+            r#"
+            [[id a b c]; [1 2 3 4]]
+            | roll right --cells-only --except [id]
+            | get 0
+            | values
+            | str join "-"
+        "#
+        ));
+
+        assert_eq!(actual.out, "1-4-2-3");
+    }
+
     struct ThirtyTwo<'a>(usize, &'a str);
 
     #[test]
@@ -233,6 +233,21 @@ fn external_command_receives_raw_binary_data() {
     })
 }
 
+#[test]
+fn line_lengths_testbin_reports_each_lines_byte_length() {
+    Playground::setup(
+        "line_lengths testbin reports each line's byte length",
+        |dirs, _| {
+            let actual = nu!(
+                cwd: dirs.test(),
+                pipeline(r#"["ab" "" "cde"] | str join (char nl) | nu --testbin line_lengths"#)
+            );
+            // `read_std` strips all newlines, so three output lines of "2", "0", "3" join as "203".
+            assert_eq!(actual.out, "203");
+        },
+    )
+}
+
 #[cfg(windows)]
 #[test]
 fn failed_command_with_semicolon_will_not_execute_following_cmds_windows() {
@@ -0,0 +1,65 @@
+use nu_test_support::nu;
+
+#[test]
+fn by_is_deterministic_across_separate_runs() {
+    let cmd = "[[id name]; [1 alice] [2 bob] [3 carol] [4 dave]] | shuffle --by {|row| $row.name} | get id | to json -r";
+
+    let first = nu!(cmd);
+    let second = nu!(cmd);
+
+    assert_eq!(first.out, second.out);
+}
+
+#[test]
+fn by_conflicts_with_range() {
+    let actual = nu!("[1 2 3] | shuffle --by {|x| $x} --range 0..1");
+
+    assert!(actual.err.contains("--by"));
+}
+
+#[test]
+fn by_conflicts_with_stable_groups_by() {
+    let actual = nu!(
+        "[[id category]; [1 a] [2 b]] | shuffle --by {|row| $row.id} --stable-groups-by category"
+    );
+
+    assert!(actual.err.contains("--by"));
+}
+
+#[test]
+fn shuffles_a_lazy_record_like_a_normal_record() {
+    let actual = nu!(
+        r#"let x = (lazy make -c ["a" "b" "c"] -g {|col| $col}); $x | shuffle | columns | sort | to json -r"#
+    );
+
+    assert_eq!(actual.out, r#"["a","b","c"]"#);
+}
+
+#[cfg(unix)]
+#[test]
+fn external_stdout_is_split_into_lines_before_shuffling() {
+    let actual = nu!(r#"^printf "a\nb\nc\n" | shuffle | sort | to json -r"#);
+
+    assert_eq!(actual.out, r#"["a","b","c"]"#);
+}
+
+#[test]
+fn values_only_keeps_record_keys_in_their_original_order() {
+    let actual = nu!("{a: 1, b: 2, c: 3} | shuffle --values-only | columns | to json -r");
+
+    assert_eq!(actual.out, r#"["a","b","c"]"#);
+}
+
+#[test]
+fn values_only_rejects_list_input() {
+    let actual = nu!("[1 2 3] | shuffle --values-only");
+
+    assert!(actual.err.contains("record"));
+}
+
+#[test]
+fn a_record_shuffle_always_keeps_each_key_with_its_own_value() {
+    let actual = nu!("{a: 1, b: 2, c: 3} | shuffle | get a");
+
+    assert_eq!(actual.out, "1");
+}
@@ -11,6 +11,24 @@ fn from_range() {
     assert_eq!(actual.out, "[\"1\",\"2\",\"3\",\"4\",\"5\"]");
 }
 
+#[test]
+fn from_small_inclusive_range_in_a_cell() {
+    let actual = nu!(r#"
+        {r: 0..3} | into string r | get r | to json -r
+        "#);
+
+    assert_eq!(actual.out, "[\"0\",\"1\",\"2\",\"3\"]");
+}
+
+#[test]
+fn from_open_ended_range_in_a_cell_is_an_error() {
+    let actual = nu!(r#"
+        {r: 0..} | into string r
+        "#);
+
+    assert!(actual.err.contains("open-ended"));
+}
+
 #[test]
 fn from_number() {
     let actual = nu!(r#"
@@ -219,3 +237,68 @@ fn int_into_string_decimals_respects_system_locale_en() {
 
     assert_eq!(actual.out, "10.0");
 }
+
+#[test]
+fn binary_without_encode_flag_is_an_error() {
+    let actual = nu!("0x[DE AD BE EF] | into string");
+
+    assert!(actual.err.contains("decode"));
+}
+
+#[test]
+fn binary_into_string_encode_hex() {
+    let actual = nu!("0x[DE AD BE EF] | into string --encode hex");
+
+    assert_eq!(actual.out, "deadbeef");
+}
+
+#[test]
+fn binary_into_string_encode_base64() {
+    let actual = nu!("0x[DE AD BE EF] | into string --encode base64");
+
+    assert_eq!(actual.out, "3q2+7w==");
+}
+
+#[test]
+fn binary_into_string_encode_base64url() {
+    let actual = nu!("0x[3E 3F] | into string --encode base64url");
+
+    assert_eq!(actual.out, "Pj8=");
+}
+
+#[test]
+fn binary_into_string_encode_rejects_unknown_encoding() {
+    let actual = nu!("0x[DE AD] | into string --encode nope");
+
+    assert!(actual.err.contains("encode"));
+}
+
+#[test]
+fn binary_into_string_encode_hex_uppercase() {
+    let actual = nu!("0x[DE AD BE EF] | into string --encode hex --case upper");
+
+    assert_eq!(actual.out, "DEADBEEF");
+}
+
+#[test]
+fn boolean_into_string_uppercase() {
+    let actual = nu!("true | into string --case upper");
+
+    assert_eq!(actual.out, "TRUE");
+}
+
+#[test]
+fn into_string_rejects_unknown_case() {
+    let actual = nu!("true | into string --case sideways");
+
+    assert!(actual.err.contains("case"));
+}
+
+#[test]
+fn binary_into_string_encode_base64_round_trips_with_decode() {
+    let actual = nu!(
+        "'hello world' | into binary | into string --encode base64 | decode base64 | into string"
+    );
+
+    assert_eq!(actual.out, "hello world");
+}
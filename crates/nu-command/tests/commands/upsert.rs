@@ -80,6 +80,103 @@ fn upsert_empty() {
     assert!(actual.err.contains("index too large (max: 0)"));
 }
 
+#[test]
+fn negative_index_replaces_from_the_end() {
+    let actual = nu!("[1 2 3] | upsert (-1) 9 | to nuon");
+
+    assert_eq!(actual.out, "[1, 2, 9]");
+}
+
+#[test]
+fn negative_index_out_of_range_is_a_clear_error() {
+    let actual = nu!("[1 2 3] | upsert (-4) 9");
+
+    assert!(actual.err.contains("out of range"));
+}
+
+#[test]
+fn string_index_into_list_is_a_clear_error() {
+    let actual = nu!("[1 2 3] | upsert foo 9");
+
+    assert!(actual.err.contains("list upserts need an integer index"));
+}
+
+#[test]
+fn if_not_exists_leaves_present_value_alone() {
+    let actual = nu!("{a: 1} | upsert a 99 --if-not-exists | get a");
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn if_not_exists_inserts_absent_value() {
+    let actual = nu!("{a: 1} | upsert b 99 --if-not-exists | get b");
+
+    assert_eq!(actual.out, "99");
+}
+
+#[test]
+fn wildcard_path_member_updates_every_row_of_a_list_of_records_column() {
+    let actual = nu!(
+        "{items: [{price: 1}, {price: 2}, {price: 3}]} | upsert items.*.price 0 | get items.price | to nuon"
+    );
+
+    assert_eq!(actual.out, "[0, 0, 0]");
+}
+
+#[test]
+fn wildcard_path_member_if_not_exists_updates_only_rows_missing_the_value() {
+    let actual = nu!(
+        "{items: [{price: 1}, {price: null}, {price: 3}]} | upsert items.*.price 0 --if-not-exists | get items.price | to nuon"
+    );
+
+    assert_eq!(actual.out, "[1, 0, 3]");
+}
+
+#[test]
+fn wildcard_path_member_errors_on_a_non_list_cell() {
+    let actual = nu!("{items: 5} | upsert items.*.price 0");
+
+    assert!(actual.err.contains("not a list"));
+}
+
+#[test]
+fn default_applies_when_the_closure_yields_nothing() {
+    let actual = nu!(
+        "{kind: 'other'} | upsert tags {|e| match $e.kind { 'fruit' => ['food'] } } --default [] | get tags | to nuon"
+    );
+
+    assert_eq!(actual.out, "[]");
+}
+
+#[test]
+fn default_does_not_override_an_explicit_null_from_the_closure() {
+    let actual = nu!("{a: 1} | upsert a {|| null } --default 99 | get a | describe");
+
+    assert_eq!(actual.out, "nothing");
+}
+
+#[test]
+fn create_path_builds_missing_intermediate_records() {
+    let actual = nu!("{} | upsert a.b.c 1 --create-path | to nuon");
+
+    assert_eq!(actual.out, "{a: {b: {c: 1}}}");
+}
+
+#[test]
+fn without_create_path_a_missing_intermediate_is_a_clear_error() {
+    let actual = nu!("{} | upsert a.b.c 1");
+
+    assert!(actual.err.contains("cannot find column"));
+}
+
+#[test]
+fn create_path_still_errors_when_an_intermediate_is_not_a_record() {
+    let actual = nu!("{a: 1} | upsert a.b 2 --create-path");
+
+    assert!(actual.err.contains("cannot find column"));
+}
+
 #[test]
 fn upsert_support_lazy_record() {
     let actual =
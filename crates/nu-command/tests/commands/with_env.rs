@@ -59,6 +59,21 @@ fn with_env_hides_variables_in_parent_scope() {
     assert_eq!(actual.out, "11");
 }
 
+#[test]
+fn with_env_forwards_var_to_child_process() {
+    let actual = nu!("with-env [MYVAR BARRRR] { nu --testbin env_require MYVAR }");
+
+    assert_eq!(actual.out, "BARRRR");
+}
+
+#[test]
+fn child_process_fails_loudly_without_required_var() {
+    let actual = nu!("nu --testbin env_require MYVAR_NOT_SET");
+
+    assert_eq!(actual.out, "");
+    assert!(actual.err.contains("MYVAR_NOT_SET"));
+}
+
 #[test]
 fn with_env_shorthand_can_not_hide_variables() {
     let actual = nu!(r#"
@@ -83,7 +83,7 @@ impl ViewCommand for ExpandCmd {
 }
 
 fn convert_value_to_string(value: Value, engine_state: &EngineState, stack: &mut Stack) -> String {
-    let (cols, vals) = collect_input(value.clone());
+    let (cols, vals) = collect_input(value.clone(), None);
 
     let has_no_head = cols.is_empty() || (cols.len() == 1 && cols[0].is_empty());
     let has_single_value = vals.len() == 1 && vals[0].len() == 1;
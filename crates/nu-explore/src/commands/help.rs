@@ -174,7 +174,7 @@ fn help_frame_data(
         .collect();
     let commands = Value::list(commands, NuSpan::unknown());
 
-    collect_input(commands)
+    collect_input(commands, None)
 }
 
 fn help_manual_data(manual: &HelpManual, aliases: &[String]) -> (Vec<String>, Vec<Vec<Value>>) {
@@ -7,7 +7,7 @@ use nu_protocol::{
 use ratatui::layout::Rect;
 
 use crate::{
-    nu_common::{collect_pipeline, has_simple_value, run_command_with_value},
+    nu_common::{collect_pipeline_with_ctrlc, has_simple_value, run_command_with_value},
     pager::Frame,
     views::{Layout, Orientation, Preview, RecordView, View, ViewConfig},
 };
@@ -87,7 +87,8 @@ impl ViewCommand for NuCmd {
 
         let is_record = matches!(pipeline, PipelineData::Value(Value::Record { .. }, ..));
 
-        let (columns, values) = collect_pipeline(pipeline);
+        let (columns, values) =
+            collect_pipeline_with_ctrlc(pipeline, engine_state.ctrlc.clone(), None);
 
         if let Some(value) = has_simple_value(&values) {
             let text = value.into_abbreviated_string(&engine_state.config);
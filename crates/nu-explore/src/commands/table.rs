@@ -99,6 +99,7 @@ impl ViewCommand for TableCmd {
             ConfigOption::boolean(":table group", "Lines are lines", "table.line_index"),
 
             ConfigOption::boolean(":table group", "Show cursor", "table.show_cursor"),
+            ConfigOption::boolean(":table group", "Summarize nested records/lists as '{N fields}' / '[N items]' placeholders", "table.summarize_nested"),
 
             ConfigOption::new(":table group", "Color of selected cell", "table.selected_cell", default_color_list()),
             ConfigOption::new(":table group", "Color of selected row", "table.selected_row", default_color_list()),
@@ -180,7 +181,7 @@ impl ViewCommand for TableCmd {
         let value = value.unwrap_or_default();
         let is_record = matches!(value, Value::Record { .. });
 
-        let (columns, data) = collect_input(value);
+        let (columns, data) = collect_input(value, None);
 
         let mut view = RecordView::new(columns, data);
 
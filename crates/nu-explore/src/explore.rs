@@ -49,6 +49,12 @@ impl Command for Explore {
                 "When quitting, output the value of the cell the cursor was on",
                 Some('p'),
             )
+            .named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "Only collect these columns, in this order, skipping the rest -- reduces memory use on wide tables",
+                None,
+            )
             .category(Category::Viewers)
     }
 
@@ -67,6 +73,7 @@ impl Command for Explore {
         let show_index: bool = call.has_flag("index");
         let is_reverse: bool = call.has_flag("reverse");
         let peek_value: bool = call.has_flag("peek");
+        let columns: Option<Vec<String>> = call.get_flag(engine_state, stack, "columns")?;
 
         let ctrlc = engine_state.ctrlc.clone();
         let nu_config = engine_state.get_config();
@@ -92,7 +99,7 @@ impl Command for Explore {
         config.exit_esc = exit_esc;
         config.show_banner = show_banner;
 
-        let result = run_pager(engine_state, &mut stack.clone(), ctrlc, input, config);
+        let result = run_pager(engine_state, &mut stack.clone(), ctrlc, input, config, columns);
 
         match result {
             Ok(Some(value)) => Ok(PipelineData::Value(value, None)),
@@ -127,6 +134,11 @@ impl Command for Explore {
                 example: r#"open file.json | explore -p | to json | save part.json"#,
                 result: None,
             },
+            Example {
+                description: "Explore only two columns of a wide table, to save memory",
+                example: r#"ls | explore --columns [name size]"#,
+                result: None,
+            },
         ]
     }
 }
@@ -15,7 +15,7 @@ use commands::{
     config::ConfigCmd, default_color_list, ConfigOption, ConfigShowCmd, ExpandCmd, HelpCmd,
     HelpManual, NuCmd, QuitCmd, TableCmd, TryCmd, TweakCmd,
 };
-use nu_common::{collect_pipeline, has_simple_value, CtrlC};
+use nu_common::{collect_pipeline_with_ctrlc, has_simple_value, CtrlC};
 use nu_protocol::{
     engine::{EngineState, Stack},
     PipelineData, Value,
@@ -37,11 +37,12 @@ fn run_pager(
     ctrlc: CtrlC,
     input: PipelineData,
     config: PagerConfig,
+    columns: Option<Vec<String>>,
 ) -> io::Result<Option<Value>> {
     let mut p = Pager::new(config.clone());
 
     let is_record = matches!(input, PipelineData::Value(Value::Record { .. }, ..));
-    let (columns, data) = collect_pipeline(input);
+    let (columns, data) = collect_pipeline_with_ctrlc(input, ctrlc.clone(), columns.as_deref());
 
     let commands = create_command_registry();
 
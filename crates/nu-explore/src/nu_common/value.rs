@@ -2,16 +2,28 @@ use std::collections::HashMap;
 
 use nu_engine::get_columns;
 use nu_protocol::{
-    ast::PathMember, record, ListStream, PipelineData, PipelineMetadata, RawStream, Value,
+    ast::PathMember, into_code, record, ListStream, PipelineData, PipelineMetadata, RawStream,
+    Value,
 };
 
-use super::NuSpan;
+use super::{CtrlC, NuSpan};
 
 pub fn collect_pipeline(input: PipelineData) -> (Vec<String>, Vec<Vec<Value>>) {
+    collect_pipeline_with_ctrlc(input, None, None)
+}
+
+/// `columns`, when given, projects the collected dataset down to just those columns (in the
+/// order given), skipping the per-row lookup for every other column -- useful for pre-selecting
+/// columns on a wide table before `explore` materializes the whole thing into memory.
+pub fn collect_pipeline_with_ctrlc(
+    input: PipelineData,
+    ctrlc: CtrlC,
+    columns: Option<&[String]>,
+) -> (Vec<String>, Vec<Vec<Value>>) {
     match input {
         PipelineData::Empty => (vec![], vec![]),
-        PipelineData::Value(value, ..) => collect_input(value),
-        PipelineData::ListStream(stream, ..) => collect_list_stream(stream),
+        PipelineData::Value(value, ..) => collect_input(value, columns),
+        PipelineData::ListStream(stream, ..) => collect_list_stream(stream, ctrlc, columns),
         PipelineData::ExternalStream {
             stdout,
             stderr,
@@ -23,13 +35,38 @@ pub fn collect_pipeline(input: PipelineData) -> (Vec<String>, Vec<Vec<Value>>) {
     }
 }
 
-fn collect_list_stream(mut stream: ListStream) -> (Vec<String>, Vec<Vec<Value>>) {
+/// Keep only the columns named in `wanted`, in `wanted`'s order, dropping any name that isn't
+/// actually present in `columns`. A `None` projection passes `columns` through unchanged.
+fn project_columns(columns: Vec<String>, wanted: Option<&[String]>) -> Vec<String> {
+    match wanted {
+        None => columns,
+        Some(wanted) => wanted
+            .iter()
+            .filter(|name| columns.contains(name))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Drain a list stream into records, stopping early (and keeping whatever was collected so
+/// far) if `ctrlc` is set partway through, so `explore` on an endless stream can be cancelled.
+fn collect_list_stream(
+    mut stream: ListStream,
+    ctrlc: CtrlC,
+    columns: Option<&[String]>,
+) -> (Vec<String>, Vec<Vec<Value>>) {
     let mut records = vec![];
     for item in stream.by_ref() {
+        if let Some(ctrlc) = &ctrlc {
+            if ctrlc.load(std::sync::atomic::Ordering::Relaxed) {
+                break;
+            }
+        }
+
         records.push(item);
     }
 
-    let mut cols = get_columns(&records);
+    let mut cols = project_columns(get_columns(&records), columns);
     let data = convert_records_to_dataset(&cols, records);
 
     // trying to deal with 'non-standard input'
@@ -43,6 +80,18 @@ fn collect_list_stream(mut stream: ListStream) -> (Vec<String>, Vec<Vec<Value>>)
     (cols, data)
 }
 
+/// Collect a raw stream into a `Value`, preferring a `String` but falling back to
+/// `Value::Binary` when the bytes aren't valid UTF-8 rather than lossily replacing them.
+fn raw_stream_into_value(stream: RawStream, span: NuSpan) -> Value {
+    match stream.into_bytes() {
+        Ok(bytes) => match String::from_utf8(bytes.item) {
+            Ok(string) => Value::string(string, span),
+            Err(error) => Value::binary(error.into_bytes(), span),
+        },
+        Err(error) => Value::error(error, span),
+    }
+}
+
 fn collect_external_stream(
     stdout: Option<RawStream>,
     stderr: Option<RawStream>,
@@ -53,26 +102,26 @@ fn collect_external_stream(
     let mut columns = vec![];
     let mut data = vec![];
     if let Some(stdout) = stdout {
-        let value = stdout.into_string().map_or_else(
-            |error| Value::error(error, span),
-            |string| Value::string(string.item, span),
-        );
+        let value = raw_stream_into_value(stdout, span);
 
         columns.push(String::from("stdout"));
         data.push(value);
     }
     if let Some(stderr) = stderr {
-        let value = stderr.into_string().map_or_else(
-            |error| Value::error(error, span),
-            |string| Value::string(string.item, span),
-        );
+        let value = raw_stream_into_value(stderr, span);
 
         columns.push(String::from("stderr"));
         data.push(value);
     }
     if let Some(exit_code) = exit_code {
         let list = exit_code.collect::<Vec<_>>();
-        let val = Value::list(list, span);
+        // An external process has a single exit code; showing it as a one-element list is
+        // confusing in explore, so flatten to a plain int and only fall back to a list if
+        // the stream (unusually) carried more than one.
+        let val = match <[Value; 1]>::try_from(list) {
+            Ok([value]) => value,
+            Err(list) => Value::list(list, span),
+        };
 
         columns.push(String::from("exit_code"));
         data.push(val);
@@ -86,13 +135,29 @@ fn collect_external_stream(
     (columns, vec![data])
 }
 
-/// Try to build column names and a table grid.
-pub fn collect_input(value: Value) -> (Vec<String>, Vec<Vec<Value>>) {
+/// Try to build column names and a table grid. `columns`, when given, projects the result
+/// down to just those columns (see [`project_columns`]); other value shapes without columns
+/// of their own (strings, errors, scalars) ignore the projection, since there's nothing to
+/// select from.
+pub fn collect_input(value: Value, columns: Option<&[String]>) -> (Vec<String>, Vec<Vec<Value>>) {
     let span = value.span();
     match value {
-        Value::Record { val: record, .. } => (record.cols, vec![record.vals]),
+        Value::Record { val: record, .. } => match columns {
+            None => (record.cols, vec![record.vals]),
+            Some(wanted) => {
+                let mut cols = Vec::new();
+                let mut vals = Vec::new();
+                for name in wanted {
+                    if let Some(pos) = record.cols.iter().position(|col| col == name) {
+                        cols.push(record.cols[pos].clone());
+                        vals.push(record.vals[pos].clone());
+                    }
+                }
+                (cols, vec![vals])
+            }
+        },
         Value::List { vals, .. } => {
-            let mut columns = get_columns(&vals);
+            let mut columns = project_columns(get_columns(&vals), columns);
             let data = convert_records_to_dataset(&columns, vals);
 
             if columns.is_empty() && !data.is_empty() {
@@ -111,33 +176,42 @@ pub fn collect_input(value: Value) -> (Vec<String>, Vec<Vec<Value>>) {
             (vec![String::from("")], lines)
         }
         Value::LazyRecord { val, .. } => match val.collect() {
-            Ok(value) => collect_input(value),
+            Ok(value) => collect_input(value, columns),
             Err(_) => (
                 vec![String::from("")],
                 vec![vec![Value::lazy_record(val, span)]],
             ),
         },
         Value::Nothing { .. } => (vec![], vec![]),
+        Value::Error { error, .. } => {
+            let columns = vec![String::from("error"), String::from("msg")];
+            let row = vec![
+                Value::string(into_code(&error).unwrap_or_default(), span),
+                Value::string(error.to_string(), span),
+            ];
+
+            (columns, vec![row])
+        }
         value => (vec![String::from("")], vec![vec![value]]),
     }
 }
 
 fn convert_records_to_dataset(cols: &Vec<String>, records: Vec<Value>) -> Vec<Vec<Value>> {
     if !cols.is_empty() {
-        create_table_for_record(cols, &records)
-    } else if cols.is_empty() && records.is_empty() {
-        vec![]
-    } else if cols.len() == records.len() {
-        vec![records]
-    } else {
-        // I am not sure whether it's good to return records as its length LIKELY
-        // will not match columns, which makes no sense......
-        //
-        // BUT...
-        // we can represent it as a list; which we do
+        return create_table_for_record(cols, &records);
+    }
 
-        records.into_iter().map(|record| vec![record]).collect()
+    if records.is_empty() {
+        return vec![];
     }
+
+    // `get_columns` returns an empty `cols` both when `records` holds a mix of records with no
+    // columns in common and when it holds non-record values entirely, so a length-based
+    // heuristic (e.g. comparing `cols.len()` to `records.len()`) can't tell those apart and will
+    // misfire whenever the count happens to line up (a 2-element list with 2 columns, say). Since
+    // there are no common columns to lay out either way, give each element its own row
+    // regardless of whether it's a bare record or a scalar.
+    records.into_iter().map(|record| vec![record]).collect()
 }
 
 fn create_table_for_record(headers: &[String], items: &[Value]) -> Vec<Vec<Value>> {
@@ -179,6 +253,19 @@ fn record_lookup_value(item: &Value, header: &str) -> Value {
     }
 }
 
+/// `{N fields}` / `[N items]` placeholder text for a record or list value, so explore's grid
+/// can show a cell that nests another collection without rendering it in full. Returns `None`
+/// for leaf (non-collection) values, which callers should keep rendering as-is. This only
+/// produces display text -- the underlying cell `Value` is left untouched, so e.g. the `expand`
+/// command can still drill into the real nested value.
+pub fn summarize_nested_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Record { val, .. } => Some(format!("{{{} fields}}", val.cols.len())),
+        Value::List { vals, .. } => Some(format!("[{} items]", vals.len())),
+        _ => None,
+    }
+}
+
 pub fn create_map(value: &Value) -> Option<HashMap<String, Value>> {
     Some(
         value
@@ -201,3 +288,187 @@ pub fn nu_str<S: AsRef<str>>(s: S) -> Value {
 fn unknown_error_value() -> Value {
     Value::string(String::from("❎"), NuSpan::unknown())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    use super::*;
+
+    /// Yields ints 0..5, flipping `ctrlc` to `true` right before it would hand out
+    /// the third item, simulating an interrupt arriving partway through a drain.
+    struct InterruptPartway {
+        ctrlc: Arc<AtomicBool>,
+        next: i64,
+        trigger_at: i64,
+    }
+
+    impl Iterator for InterruptPartway {
+        type Item = Value;
+
+        fn next(&mut self) -> Option<Value> {
+            if self.next >= 5 {
+                return None;
+            }
+            if self.next == self.trigger_at {
+                self.ctrlc.store(true, Ordering::Relaxed);
+            }
+            let value = Value::test_int(self.next);
+            self.next += 1;
+            Some(value)
+        }
+    }
+
+    #[test]
+    fn collect_list_stream_stops_when_interrupted_partway_through() {
+        let ctrlc = Arc::new(AtomicBool::new(false));
+        let stream = ListStream::from_stream(
+            InterruptPartway {
+                ctrlc: ctrlc.clone(),
+                next: 0,
+                trigger_at: 2,
+            },
+            None,
+        );
+
+        let (_, data) = collect_list_stream(stream, Some(ctrlc), None);
+
+        // The interrupt fires while fetching the 3rd item (index 2), so only the
+        // first two are collected instead of all 5.
+        assert_eq!(data.len(), 2);
+    }
+
+    #[test]
+    fn collect_input_handles_a_list_whose_length_matches_its_column_count() {
+        // A 2-element list of records with 2 columns each: `cols.len() == records.len()`
+        // coincidentally, which used to get misread as a signal to collapse the whole list into
+        // a single row instead of laying it out as a proper 2x2 table.
+        let span = NuSpan::unknown();
+        let list = Value::list(
+            vec![
+                Value::record(record! { "a" => Value::test_int(1), "b" => Value::test_int(2) }, span),
+                Value::record(record! { "a" => Value::test_int(3), "b" => Value::test_int(4) }, span),
+            ],
+            span,
+        );
+
+        let (cols, data) = collect_input(list, None);
+
+        assert_eq!(cols, vec![String::from("a"), String::from("b")]);
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0], vec![Value::test_int(1), Value::test_int(2)]);
+        assert_eq!(data[1], vec![Value::test_int(3), Value::test_int(4)]);
+    }
+
+    #[test]
+    fn collect_input_projects_to_the_requested_columns_only() {
+        let span = NuSpan::unknown();
+        let list = Value::list(
+            vec![
+                Value::record(
+                    record! {
+                        "a" => Value::test_int(1),
+                        "b" => Value::test_int(2),
+                        "c" => Value::test_int(3),
+                        "d" => Value::test_int(4),
+                    },
+                    span,
+                ),
+                Value::record(
+                    record! {
+                        "a" => Value::test_int(5),
+                        "b" => Value::test_int(6),
+                        "c" => Value::test_int(7),
+                        "d" => Value::test_int(8),
+                    },
+                    span,
+                ),
+            ],
+            span,
+        );
+
+        let wanted = vec![String::from("b"), String::from("d")];
+        let (cols, data) = collect_input(list, Some(&wanted));
+
+        assert_eq!(cols, vec![String::from("b"), String::from("d")]);
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0], vec![Value::test_int(2), Value::test_int(4)]);
+        assert_eq!(data[1], vec![Value::test_int(6), Value::test_int(8)]);
+    }
+
+    #[test]
+    fn collect_input_shows_an_error_value_as_readable_columns() {
+        let span = NuSpan::unknown();
+        let error = Value::error(
+            nu_protocol::ShellError::DivisionByZero { span },
+            span,
+        );
+
+        let (cols, data) = collect_input(error, None);
+
+        assert_eq!(cols, vec![String::from("error"), String::from("msg")]);
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0].len(), 2);
+        assert!(matches!(data[0][0], Value::String { .. }));
+        assert!(matches!(data[0][1], Value::String { .. }));
+    }
+
+    #[test]
+    fn collect_external_stream_flattens_a_single_exit_code_to_an_int() {
+        let span = NuSpan::unknown();
+        let exit_code = ListStream::from_stream(std::iter::once(Value::test_int(0)), None);
+
+        let (columns, data) = collect_external_stream(None, None, Some(exit_code), None, span);
+
+        assert_eq!(columns, vec![String::from("exit_code")]);
+        assert!(matches!(data[0][0], Value::Int { val: 0, .. }));
+    }
+
+    #[test]
+    fn summarize_nested_value_placeholders_a_nested_record_column() {
+        // A table whose column holds a nested record, as produced for e.g. `[[meta]; [{a: 1, b: 2}]] | explore`.
+        let span = NuSpan::unknown();
+        let table = Value::list(
+            vec![Value::record(
+                record! { "meta" => Value::record(record! { "a" => Value::test_int(1), "b" => Value::test_int(2) }, span) },
+                span,
+            )],
+            span,
+        );
+
+        let (cols, data) = collect_input(table, None);
+        assert_eq!(cols, vec![String::from("meta")]);
+
+        let cell = &data[0][0];
+        assert!(matches!(cell, Value::Record { .. }));
+        assert_eq!(summarize_nested_value(cell), Some(String::from("{2 fields}")));
+    }
+
+    #[test]
+    fn summarize_nested_value_placeholders_a_nested_list() {
+        let list = Value::test_list(vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)]);
+        assert_eq!(summarize_nested_value(&list), Some(String::from("[3 items]")));
+    }
+
+    #[test]
+    fn summarize_nested_value_leaves_leaf_values_alone() {
+        assert_eq!(summarize_nested_value(&Value::test_int(5)), None);
+        assert_eq!(summarize_nested_value(&Value::test_string("hi")), None);
+    }
+
+    #[test]
+    fn collect_external_stream_keeps_multiple_exit_codes_as_a_list() {
+        let span = NuSpan::unknown();
+        let exit_code = ListStream::from_stream(
+            vec![Value::test_int(0), Value::test_int(1)].into_iter(),
+            None,
+        );
+
+        let (_, data) = collect_external_stream(None, None, Some(exit_code), None, span);
+
+        assert!(matches!(data[0][0], Value::List { .. }));
+    }
+}
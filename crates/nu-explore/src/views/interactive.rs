@@ -13,7 +13,7 @@ use ratatui::{
 };
 
 use crate::{
-    nu_common::{collect_pipeline, run_command_with_value},
+    nu_common::{collect_pipeline_with_ctrlc, run_command_with_value},
     pager::{report::Report, Frame, Transition, ViewInfo},
     util::create_map,
 };
@@ -292,7 +292,8 @@ fn run_command(
 
     let is_record = matches!(pipeline, PipelineData::Value(Value::Record { .. }, ..));
 
-    let (columns, values) = collect_pipeline(pipeline);
+    let (columns, values) =
+        collect_pipeline_with_ctrlc(pipeline, engine_state.ctrlc.clone(), None);
 
     let mut view = RecordView::new(columns, values);
     if is_record {
@@ -13,7 +13,7 @@ use nu_protocol::{
 use ratatui::{layout::Rect, widgets::Block};
 
 use crate::{
-    nu_common::{collect_input, lscolorize, NuConfig, NuSpan, NuStyle, NuText},
+    nu_common::{collect_input, lscolorize, summarize_nested_value, NuConfig, NuSpan, NuStyle, NuText},
     pager::{
         report::{Report, Severity},
         ConfigMap, Frame, Transition, ViewInfo,
@@ -210,7 +210,12 @@ impl<'a> RecordView<'a> {
 
     fn create_tablew(&'a self, cfg: ViewConfig<'a>) -> TableW<'a> {
         let layer = self.get_layer_last();
-        let mut data = convert_records_to_string(&layer.records, cfg.nu_config, cfg.style_computer);
+        let mut data = convert_records_to_string(
+            &layer.records,
+            cfg.nu_config,
+            cfg.style_computer,
+            self.theme.table.summarize_nested,
+        );
 
         lscolorize(&layer.columns, &mut data, cfg.lscolors);
 
@@ -314,6 +319,7 @@ impl View for RecordView<'_> {
             &self.get_layer_last().records,
             &NuConfig::default(),
             &style_computer,
+            self.theme.table.summarize_nested,
         );
 
         data.iter().flatten().cloned().collect()
@@ -626,7 +632,7 @@ fn handle_key_event_cursor_mode(view: &mut RecordView, key: &KeyEvent) -> Option
 }
 
 fn create_layer(value: Value) -> RecordLayer<'static> {
-    let (columns, values) = collect_input(value);
+    let (columns, values) = collect_input(value, None);
 
     RecordLayer::new(columns, values)
 }
@@ -665,13 +671,17 @@ fn convert_records_to_string(
     records: &[Vec<Value>],
     cfg: &NuConfig,
     style_computer: &StyleComputer,
+    summarize_nested: bool,
 ) -> Vec<Vec<NuText>> {
     records
         .iter()
         .map(|row| {
             row.iter()
                 .map(|value| {
-                    let text = value.clone().into_abbreviated_string(cfg);
+                    let text = summarize_nested
+                        .then(|| summarize_nested_value(value))
+                        .flatten()
+                        .unwrap_or_else(|| value.clone().into_abbreviated_string(cfg));
                     let float_precision = cfg.float_precision as usize;
 
                     make_styled_string(style_computer, text, Some(value), float_precision)
@@ -861,6 +871,7 @@ fn theme_from_config(config: &ConfigMap) -> TableTheme {
 
     theme.table.show_header = config_get_bool(config, "show_head", true);
     theme.table.show_index = config_get_bool(config, "show_index", false);
+    theme.table.summarize_nested = config_get_bool(config, "summarize_nested", false);
 
     theme.table.padding_index_left = config_get_usize(config, "padding_index_left", 2);
     theme.table.padding_index_right = config_get_usize(config, "padding_index_right", 1);
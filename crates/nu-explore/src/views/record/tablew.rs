@@ -53,6 +53,7 @@ pub struct TableStyle {
     pub padding_index_right: usize,
     pub padding_column_left: usize,
     pub padding_column_right: usize,
+    pub summarize_nested: bool,
 }
 
 impl<'a> TableW<'a> {
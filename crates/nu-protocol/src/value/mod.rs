@@ -1159,9 +1159,55 @@ impl Value {
         &mut self,
         cell_path: &[PathMember],
         new_val: Value,
+    ) -> Result<(), ShellError> {
+        self.upsert_data_at_cell_path_helper(cell_path, new_val, false)
+    }
+
+    /// Like [`Value::upsert_data_at_cell_path`], but auto-vivifies missing intermediate
+    /// values into empty records instead of erroring, so a deep path like `a.b.c` can be
+    /// built from scratch (e.g. starting from `{}` or explicit `null`). An intermediate
+    /// that exists but isn't a record (or list, for wildcard members) still errors.
+    pub fn upsert_data_at_cell_path_create_path(
+        &mut self,
+        cell_path: &[PathMember],
+        new_val: Value,
+    ) -> Result<(), ShellError> {
+        self.upsert_data_at_cell_path_helper(cell_path, new_val, true)
+    }
+
+    fn upsert_data_at_cell_path_helper(
+        &mut self,
+        cell_path: &[PathMember],
+        new_val: Value,
+        create_path: bool,
     ) -> Result<(), ShellError> {
         match cell_path.first() {
             Some(path_member) => match path_member {
+                PathMember::String {
+                    val: col_name,
+                    span,
+                    ..
+                } if col_name == "*" => match self {
+                    // A wildcard member applies the rest of the cell path to every element of
+                    // a list-valued cell instead of selecting a single column by name, e.g.
+                    // `items.*.price` updates `price` on every record in the `items` list.
+                    Value::List { vals, .. } => {
+                        for val in vals.iter_mut() {
+                            val.upsert_data_at_cell_path_helper(
+                                &cell_path[1..],
+                                new_val.clone(),
+                                create_path,
+                            )?
+                        }
+                    }
+                    Value::Error { error, .. } => return Err(*error.to_owned()),
+                    v => {
+                        return Err(ShellError::NotAList {
+                            dst_span: *span,
+                            src_span: v.span(),
+                        })
+                    }
+                },
                 PathMember::String {
                     val: col_name,
                     span,
@@ -1175,9 +1221,10 @@ impl Value {
                                     for (col, val) in record.iter_mut() {
                                         if col == col_name {
                                             found = true;
-                                            val.upsert_data_at_cell_path(
+                                            val.upsert_data_at_cell_path_helper(
                                                 &cell_path[1..],
                                                 new_val.clone(),
+                                                create_path,
                                             )?
                                         }
                                     }
@@ -1188,15 +1235,26 @@ impl Value {
                                         } else {
                                             let mut new_col =
                                                 Value::record(Record::new(), new_val.span());
-                                            new_col.upsert_data_at_cell_path(
+                                            new_col.upsert_data_at_cell_path_helper(
                                                 &cell_path[1..],
                                                 new_val,
+                                                create_path,
                                             )?;
                                             vals.push(new_col);
                                             break;
                                         }
                                     }
                                 }
+                                Value::Nothing { .. } if create_path => {
+                                    let mut new_col =
+                                        Value::record(Record::new(), new_val.span());
+                                    new_col.upsert_data_at_cell_path_helper(
+                                        cell_path,
+                                        new_val.clone(),
+                                        create_path,
+                                    )?;
+                                    *val = new_col;
+                                }
                                 Value::Error { error, .. } => return Err(*error.to_owned()),
                                 v => {
                                     return Err(ShellError::CantFindColumn {
@@ -1214,7 +1272,11 @@ impl Value {
                         for (col, val) in record.iter_mut() {
                             if col == col_name {
                                 found = true;
-                                val.upsert_data_at_cell_path(&cell_path[1..], new_val.clone())?
+                                val.upsert_data_at_cell_path_helper(
+                                    &cell_path[1..],
+                                    new_val.clone(),
+                                    create_path,
+                                )?
                             }
                         }
                         if !found {
@@ -1222,7 +1284,11 @@ impl Value {
                                 new_val
                             } else {
                                 let mut new_col = Value::record(Record::new(), new_val.span());
-                                new_col.upsert_data_at_cell_path(&cell_path[1..], new_val)?;
+                                new_col.upsert_data_at_cell_path_helper(
+                                    &cell_path[1..],
+                                    new_val,
+                                    create_path,
+                                )?;
                                 new_col
                             };
 
@@ -1232,9 +1298,21 @@ impl Value {
                     Value::LazyRecord { val, .. } => {
                         // convert to Record first.
                         let mut record = val.collect()?;
-                        record.upsert_data_at_cell_path(cell_path, new_val)?;
+                        record.upsert_data_at_cell_path_helper(cell_path, new_val, create_path)?;
                         *self = record
                     }
+                    Value::Nothing { .. } if create_path => {
+                        // An absent intermediate value is auto-vivified into an empty
+                        // record so that deep paths like `a.b.c` can be created from
+                        // scratch (e.g. starting from `{}` or explicit `null`).
+                        let mut new_record = Value::record(Record::new(), new_val.span());
+                        new_record.upsert_data_at_cell_path_helper(
+                            cell_path,
+                            new_val,
+                            create_path,
+                        )?;
+                        *self = new_record;
+                    }
                     Value::Error { error, .. } => return Err(*error.to_owned()),
                     v => {
                         return Err(ShellError::CantFindColumn {
@@ -1249,7 +1327,11 @@ impl Value {
                 } => match self {
                     Value::List { vals, .. } => {
                         if let Some(v) = vals.get_mut(*row_num) {
-                            v.upsert_data_at_cell_path(&cell_path[1..], new_val)?
+                            v.upsert_data_at_cell_path_helper(
+                                &cell_path[1..],
+                                new_val,
+                                create_path,
+                            )?
                         } else if vals.len() == *row_num && cell_path.len() == 1 {
                             // If the upsert is at 1 + the end of the list, it's OK.
                             // Otherwise, it's prohibited.
@@ -3986,4 +4068,101 @@ mod tests {
             assert_eq!("-0316-02-11T06:13:20+00:00", formatted);
         }
     }
+
+    mod upsert_data_at_cell_path {
+        use super::*;
+        use crate::ast::PathMember;
+
+        fn member(name: &str) -> PathMember {
+            PathMember::String {
+                val: name.to_string(),
+                span: Span::unknown(),
+                optional: false,
+            }
+        }
+
+        #[test]
+        fn creates_missing_intermediate_records_from_an_empty_record() {
+            let mut value = Value::test_record(Record::new());
+            let path = vec![member("a"), member("b"), member("c")];
+
+            value
+                .upsert_data_at_cell_path_create_path(&path, Value::test_int(1))
+                .unwrap();
+
+            assert_eq!(
+                value
+                    .follow_cell_path(&path, false)
+                    .unwrap()
+                    .as_int()
+                    .unwrap(),
+                1
+            );
+        }
+
+        #[test]
+        fn creates_missing_intermediate_records_from_null() {
+            let mut value = Value::nothing(Span::unknown());
+            let path = vec![member("a"), member("b")];
+
+            value
+                .upsert_data_at_cell_path_create_path(&path, Value::test_int(2))
+                .unwrap();
+
+            assert_eq!(
+                value
+                    .follow_cell_path(&path, false)
+                    .unwrap()
+                    .as_int()
+                    .unwrap(),
+                2
+            );
+        }
+
+        #[test]
+        fn without_create_path_a_missing_intermediate_still_errors() {
+            let mut value = Value::nothing(Span::unknown());
+            let path = vec![member("a"), member("b")];
+
+            assert!(value
+                .upsert_data_at_cell_path(&path, Value::test_int(2))
+                .is_err());
+        }
+
+        #[test]
+        fn create_path_still_errors_when_an_intermediate_is_a_non_record() {
+            let mut value = Value::test_record(record! { "a" => Value::test_int(1) });
+            let path = vec![member("a"), member("b")];
+
+            assert!(value
+                .upsert_data_at_cell_path_create_path(&path, Value::test_int(2))
+                .is_err());
+        }
+
+        #[test]
+        fn wildcard_member_applies_to_every_element_of_a_list() {
+            let mut value = Value::test_list(vec![
+                Value::test_record(record! { "price" => Value::test_int(1) }),
+                Value::test_record(record! { "price" => Value::test_int(2) }),
+            ]);
+            let path = vec![member("*"), member("price")];
+
+            value
+                .upsert_data_at_cell_path(&path, Value::test_int(0))
+                .unwrap();
+
+            let Value::List { vals, .. } = value else {
+                panic!("expected a list");
+            };
+            for val in vals {
+                assert_eq!(
+                    val.follow_cell_path(&[member("price")], false)
+                        .unwrap()
+                        .as_int()
+                        .unwrap(),
+                    0
+                );
+            }
+        }
+    }
 }
@@ -1,35 +1,121 @@
 use std::path::PathBuf;
 
+use miette::Diagnostic;
 use nu_engine::{env::current_dir, eval_block};
 use nu_parser::parse;
 use nu_protocol::engine::{Stack, StateWorkingSet, VirtualPath};
-use nu_protocol::{report_error, PipelineData};
+use nu_protocol::{report_error, ParseError, PipelineData};
+use thiserror::Error;
 
 // Virtual std directory unlikely to appear in user's file system
 const NU_STDLIB_VIRTUAL_DIR: &str = "NU_STDLIB_VIRTUAL_DIR";
 
+/// All the parse errors hit while loading the standard library, bundled into a single
+/// diagnostic so embedders get one `Result` instead of errors only being printed.
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to load the standard library: {} parse error(s)", errors.len())]
+struct StandardLibraryLoadError {
+    #[related]
+    errors: Vec<ParseError>,
+}
+
+/// Which prelude imports [`load_standard_library`] brings into the global namespace after
+/// loading `std`'s modules. `std`'s modules are always loaded and reachable via their full
+/// path (e.g. `std dirs enter`, `std pwd`) regardless of what's in the prelude -- this only
+/// controls what additionally gets imported unqualified, for embedders that don't want e.g.
+/// `std dirs`'s short aliases (`g`/`n`/`p`) polluting the global namespace.
+#[derive(Debug, Clone)]
+pub struct Prelude {
+    /// Names to import unqualified from `std dirs`, e.g. `enter`, `shells`, `g`, `n`, `p`, `dexit`.
+    pub dirs: Vec<String>,
+    /// Whether to import `std pwd` unqualified.
+    pub pwd: bool,
+}
+
+impl Prelude {
+    /// No prelude imports at all.
+    pub fn empty() -> Self {
+        Self {
+            dirs: Vec::new(),
+            pwd: false,
+        }
+    }
+}
+
+impl Default for Prelude {
+    fn default() -> Self {
+        Self {
+            dirs: ["enter", "shells", "g", "n", "p", "dexit"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            pwd: true,
+        }
+    }
+}
+
 pub fn load_standard_library(
     engine_state: &mut nu_protocol::engine::EngineState,
 ) -> Result<(), miette::ErrReport> {
-    let (block, delta) = {
-        // Using full virtual path to avoid potential conflicts with user having 'std' directory
-        // in their working directory.
-        let std_dir = PathBuf::from(NU_STDLIB_VIRTUAL_DIR).join("std");
+    load_standard_library_with_prelude(engine_state, Prelude::default())
+}
 
-        let mut std_files = vec![
+/// Like [`load_standard_library`], but with a custom [`Prelude`] instead of the default one.
+pub fn load_standard_library_with_prelude(
+    engine_state: &mut nu_protocol::engine::EngineState,
+    prelude: Prelude,
+) -> Result<(), miette::ErrReport> {
+    load_standard_library_from_files(
+        engine_state,
+        vec![
             ("mod.nu", include_str!("../std/mod.nu")),
             ("testing.nu", include_str!("../std/testing.nu")),
             ("dirs.nu", include_str!("../std/dirs.nu")),
             ("dt.nu", include_str!("../std/dt.nu")),
+            ("env.nu", include_str!("../std/env.nu")),
             ("help.nu", include_str!("../std/help.nu")),
             ("iter.nu", include_str!("../std/iter.nu")),
             ("log.nu", include_str!("../std/log.nu")),
             ("assert.nu", include_str!("../std/assert.nu")),
+            ("fs.nu", include_str!("../std/fs.nu")),
+            ("record.nu", include_str!("../std/record.nu")),
             ("xml.nu", include_str!("../std/xml.nu")),
             ("input.nu", include_str!("../std/input.nu")),
             ("math.nu", include_str!("../std/math.nu")),
-        ];
+            ("retry.nu", include_str!("../std/retry.nu")),
+            ("semver.nu", include_str!("../std/semver.nu")),
+            ("progress.nu", include_str!("../std/progress.nu")),
+            ("str.nu", include_str!("../std/str.nu")),
+        ],
+        &prelude,
+    )
+}
+
+/// Does the actual work of [`load_standard_library`], taking the `std` module's files
+/// explicitly so tests can load a deliberately-corrupted file list without touching the
+/// embedded `include_str!` sources.
+fn load_standard_library_from_files(
+    engine_state: &mut nu_protocol::engine::EngineState,
+    mut std_files: Vec<(&str, &str)>,
+    prelude: &Prelude,
+) -> Result<(), miette::ErrReport> {
+    // Using full virtual path to avoid potential conflicts with user having 'std' directory
+    // in their working directory.
+    let std_dir = PathBuf::from(NU_STDLIB_VIRTUAL_DIR).join("std");
+    let std_dir_name = std_dir.to_string_lossy().to_string();
+
+    // A second call on an engine state that already has `std` registered would re-add the
+    // virtual module and re-run its `export-env` blocks, producing duplicate-definition
+    // parse errors. Since the module is only ever added here, its presence alone is proof
+    // the stdlib is already loaded, so just no-op.
+    if StateWorkingSet::new(engine_state)
+        .find_virtual_path(&std_dir_name)
+        .is_some()
+    {
+        return Ok(());
+    }
 
+    let (block, delta) = {
         let mut working_set = StateWorkingSet::new(engine_state);
         let mut std_virt_paths = vec![];
 
@@ -46,22 +132,30 @@ pub fn load_standard_library(
         }
 
         let std_dir = std_dir.to_string_lossy().to_string();
+        let dirs_prelude = if prelude.dirs.is_empty() {
+            String::new()
+        } else {
+            let names = prelude
+                .dirs
+                .iter()
+                .map(|name| format!("    {name}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("use std dirs [\n{names}\n]\n")
+        };
+        let pwd_prelude = if prelude.pwd { "use std pwd\n" } else { "" };
         let source = format!(
             r#"
 # Define the `std` module
 module {std_dir}
 
+# Bring every command into scope under its qualified path (e.g. `std dirs enter`),
+# regardless of the prelude -- the prelude below only controls what *also* gets
+# imported unqualified.
+use std
+
 # Prelude
-use std dirs [
-    enter
-    shells
-    g
-    n
-    p
-    dexit
-]
-use std pwd
-"#
+{dirs_prelude}{pwd_prelude}"#
         );
 
         let _ = working_set.add_virtual_path(std_dir, VirtualPath::Dir(std_virt_paths));
@@ -77,13 +171,20 @@ use std pwd
             false,
         );
 
-        if let Some(err) = working_set.parse_errors.first() {
+        // Report every parse error for CLI UX, but also collect them so we can
+        // propagate them to embedders instead of silently continuing with a broken block.
+        for err in &working_set.parse_errors {
             report_error(&working_set, err);
         }
+        let errors = working_set.parse_errors.clone();
 
         // Restore the currently parsed directory back
         working_set.currently_parsed_cwd = prev_currently_parsed_cwd;
 
+        if !errors.is_empty() {
+            return Err(StandardLibraryLoadError { errors }.into());
+        }
+
         (block, working_set.render())
     };
 
@@ -106,3 +207,58 @@ use std pwd
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::engine::EngineState;
+
+    #[test]
+    fn returns_err_when_an_embedded_file_fails_to_parse() {
+        let mut engine_state = EngineState::new();
+        let std_files = vec![
+            ("mod.nu", include_str!("../std/mod.nu")),
+            ("dirs.nu", include_str!("../std/dirs.nu")),
+            // Deliberately corrupted: an unclosed delimiter.
+            ("broken.nu", "export def broken [] {"),
+        ];
+
+        let result =
+            load_standard_library_from_files(&mut engine_state, std_files, &Prelude::default());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn an_empty_prelude_still_loads_the_modules_without_importing_their_aliases() {
+        let mut engine_state = EngineState::new();
+
+        load_standard_library_with_prelude(&mut engine_state, Prelude::empty())
+            .expect("load with an empty prelude succeeds");
+
+        assert!(
+            engine_state.find_decl(b"g", &[]).is_none(),
+            "g should not be imported into the global namespace"
+        );
+        assert!(
+            engine_state.find_decl(b"std dirs enter", &[]).is_some(),
+            "std dirs enter should still resolve by its full path"
+        );
+    }
+
+    #[test]
+    fn loading_the_standard_library_twice_is_a_no_op_on_the_second_call() {
+        let mut engine_state = EngineState::new();
+        let num_virtual_paths_before = engine_state.num_virtual_paths();
+
+        load_standard_library(&mut engine_state).expect("first load succeeds");
+        let num_virtual_paths_after_first_load = engine_state.num_virtual_paths();
+        assert!(num_virtual_paths_after_first_load > num_virtual_paths_before);
+
+        load_standard_library(&mut engine_state).expect("second load is a no-op, not an error");
+        assert_eq!(
+            engine_state.num_virtual_paths(),
+            num_virtual_paths_after_first_load
+        );
+    }
+}
@@ -165,6 +165,17 @@ impl ProcessInfo {
         }
     }
 
+    /// Individual argv entries, unlike [`Self::command`] which joins them into one string.
+    /// Empty if `/proc/<pid>/cmdline` isn't available (e.g. a zombie process).
+    pub fn command_args(&self) -> Vec<String> {
+        self.curr_proc.cmdline().unwrap_or_default()
+    }
+
+    /// UID of the process owner, `0` (root) if it couldn't be determined.
+    pub fn uid(&self) -> u32 {
+        self.curr_proc.owner()
+    }
+
     /// Get the status of the process
     pub fn status(&self) -> String {
         if let Ok(p) = self.curr_proc.stat() {
@@ -219,4 +230,18 @@ impl ProcessInfo {
     pub fn virtual_size(&self) -> u64 {
         self.curr_proc.stat().map(|p| p.vsize).unwrap_or_default()
     }
+
+    /// Bytes read from and written to disk over the sampled window, as `(read, write)`.
+    ///
+    /// `None` if `/proc/<pid>/io` couldn't be read for either sample (e.g. insufficient
+    /// permissions), or if the counters decreased (the process was replaced mid-sample).
+    pub fn disk_usage(&self) -> Option<(u64, u64)> {
+        let curr = self.curr_io.as_ref()?;
+        let prev = self.prev_io.as_ref()?;
+
+        let read = curr.read_bytes.checked_sub(prev.read_bytes)?;
+        let write = curr.write_bytes.checked_sub(prev.write_bytes)?;
+
+        Some((read, write))
+    }
 }
@@ -340,6 +340,14 @@ impl ProcessInfo {
         }
     }
 
+    /// Individual argv entries, unlike [`Self::command`] which joins them into one string
+    pub fn command_args(&self) -> Vec<String> {
+        self.curr_path
+            .as_ref()
+            .map(|path| path.cmd.clone())
+            .unwrap_or_default()
+    }
+
     /// Get the status of the process
     pub fn status(&self) -> String {
         let mut state = 7;
@@ -394,6 +402,24 @@ impl ProcessInfo {
     pub fn virtual_size(&self) -> u64 {
         self.curr_task.ptinfo.pti_virtual_size
     }
+
+    /// Bytes read from and written to disk over the sampled window, as `(read, write)`.
+    ///
+    /// `None` if resource usage info couldn't be fetched for either sample, or if the
+    /// counters decreased (the process was replaced mid-sample).
+    pub fn disk_usage(&self) -> Option<(u64, u64)> {
+        let curr = self.curr_res.as_ref()?;
+        let prev = self.prev_res.as_ref()?;
+
+        let read = curr
+            .ri_diskio_bytesread
+            .checked_sub(prev.ri_diskio_bytesread)?;
+        let write = curr
+            .ri_diskio_byteswritten
+            .checked_sub(prev.ri_diskio_byteswritten)?;
+
+        Some((read, write))
+    }
 }
 
 /// The Macos kernel returns process times in mach ticks rather than nanoseconds.  To get times in
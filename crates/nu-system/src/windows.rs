@@ -1021,6 +1021,11 @@ impl ProcessInfo {
         self.cmd.join(" ")
     }
 
+    /// Individual argv entries, unlike [`Self::command`] which joins them into one string
+    pub fn command_args(&self) -> Vec<String> {
+        self.cmd.clone()
+    }
+
     pub fn environ(&self) -> Vec<String> {
         self.environ.clone()
     }
@@ -1053,4 +1058,20 @@ impl ProcessInfo {
     pub fn virtual_size(&self) -> u64 {
         self.memory_info.private_usage
     }
+
+    /// Bytes read from and written to disk over the sampled window, as `(read, write)`.
+    ///
+    /// `None` if the counters decreased (the process was replaced mid-sample).
+    pub fn disk_usage(&self) -> Option<(u64, u64)> {
+        let read = self
+            .disk_info
+            .curr_read
+            .checked_sub(self.disk_info.prev_read)?;
+        let write = self
+            .disk_info
+            .curr_write
+            .checked_sub(self.disk_info.prev_write)?;
+
+        Some((read, write))
+    }
 }
@@ -223,6 +223,7 @@ fn main() -> Result<()> {
         match testbin.item.as_str() {
             "echo_env" => test_bins::echo_env(true),
             "echo_env_stderr" => test_bins::echo_env(false),
+            "env_require" => test_bins::env_require(),
             "cococo" => test_bins::cococo(),
             "meow" => test_bins::meow(),
             "meowb" => test_bins::meowb(),
@@ -235,6 +236,8 @@ fn main() -> Result<()> {
             "repeat_bytes" => test_bins::repeat_bytes(),
             "nu_repl" => test_bins::nu_repl(),
             "input_bytes_length" => test_bins::input_bytes_length(),
+            "line_lengths" => test_bins::line_lengths(),
+            "emit_bytes_then_hang" => test_bins::emit_bytes_then_hang(),
             _ => std::process::exit(1),
         }
         std::process::exit(0)
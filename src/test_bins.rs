@@ -135,6 +135,22 @@ pub fn fail() {
     std::process::exit(1);
 }
 
+/// Exits 0 and prints the value of a named env var if it's set; exits 1 with a message on
+/// stderr if it isn't. Unlike `echo_env`, which silently prints nothing when the var is
+/// missing, this lets tests assert that env forwarding (e.g. via `with-env`) actually failed.
+/// Example: nu --testbin env_require MYVAR
+pub fn env_require() {
+    let var = args().into_iter().nth(1).expect("needs an env var name");
+
+    match std::env::var(&var) {
+        Ok(v) => println!("{v}"),
+        Err(_) => {
+            eprintln!("env_require: environment variable '{var}' is not set");
+            std::process::exit(1);
+        }
+    }
+}
+
 /// With no parameters, will chop a character off the end of each line
 pub fn chop() {
     if did_chop_arguments() {
@@ -189,7 +205,19 @@ fn get_engine_state() -> EngineState {
 pub fn nu_repl() {
     //cwd: &str, source_lines: &[&str]) {
     let cwd = std::env::current_dir().expect("Could not get current working directory.");
-    let source_lines = args();
+    let args = args();
+
+    // `nu --testbin=nu_repl -` reads newline-separated REPL lines from stdin instead of args,
+    // so large scripts don't hit arg-length limits or need awkward per-line quoting.
+    let source_lines: Vec<String> = if args.first().map(String::as_str) == Some("-") {
+        io::stdin()
+            .lock()
+            .lines()
+            .collect::<Result<_, _>>()
+            .expect("failed to read REPL lines from stdin")
+    } else {
+        args
+    };
 
     let mut engine_state = get_engine_state();
     let mut stack = Stack::new();
@@ -331,6 +359,41 @@ pub fn input_bytes_length() {
     println!("{}", count);
 }
 
+/// Read stdin line-by-line and print each line's byte length on its own output line,
+/// including a final line that has no trailing newline. Useful for verifying that streaming
+/// filters preserve line boundaries rather than rechunking the data.
+/// Example: nu --testbin line_lengths
+pub fn line_lengths() {
+    let stdin = io::stdin();
+    for line in stdin.lock().lines() {
+        let line = line.expect("failed to read line from stdin");
+        println!("{}", line.len());
+    }
+}
+
+/// Write a fixed number of bytes to stdout, then hang forever without exiting.
+/// Useful for testing that callers enforce a timeout on a stuck external command.
+/// Example: nu --testbin emit_bytes_then_hang 5
+/// (writes "aaaaa" to stdout, then never terminates on its own)
+pub fn emit_bytes_then_hang() {
+    let args = args();
+    let count: usize = args
+        .get(1)
+        .expect("needs a byte count")
+        .parse()
+        .expect("byte count must be a number");
+
+    let mut stdout = io::stdout();
+    stdout
+        .write_all(&vec![b'a'; count])
+        .expect("writing to stdout must not fail");
+    let _ = stdout.flush();
+
+    loop {
+        std::thread::sleep(std::time::Duration::from_secs(3600));
+    }
+}
+
 fn args() -> Vec<String> {
     // skip (--testbin bin_name args)
     std::env::args().skip(2).collect()